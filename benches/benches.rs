@@ -12,7 +12,16 @@ benchmark_group!(
     base_endian_test_be,
     base_endian_test_le,
     base_endian_test_ne,
-    base_endian_test_structured
+    base_endian_test_structured,
+    bench_eq_be,
+    bench_eq_le,
+    bench_eq_ne,
+    bench_cmp_be,
+    bench_cmp_be_bytes,
+    bench_cmp_le,
+    bench_cmp_ne,
+    bench_hash_be,
+    bench_hash_ne
 );
 //benchmark_group!(benches, bench_integer_be);
 benchmark_main!(benches);
@@ -20,6 +29,10 @@ benchmark_main!(benches);
 //criterion_group!(benches, bench_integer_be);
 //criterion_main!(benches);
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use bencher::black_box;
 use simple_endian_wrapper::{BigEndian, LittleEndian};
 
 fn bench_integer_be(b: &mut Bencher) {
@@ -130,3 +143,87 @@ fn base_endian_test_structured(b: &mut Bencher) {
         }
     });
 }
+
+fn bench_eq_be(b: &mut Bencher) {
+    let values: Vec<BigEndian<u64>> = (0..1000u64).map(|v| (v % 7).into()).collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] == w[1]).count()
+    });
+}
+
+fn bench_eq_le(b: &mut Bencher) {
+    let values: Vec<LittleEndian<u64>> = (0..1000u64).map(|v| (v % 7).into()).collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] == w[1]).count()
+    });
+}
+
+fn bench_eq_ne(b: &mut Bencher) {
+    let values: Vec<u64> = (0..1000u64).map(|v| v % 7).collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] == w[1]).count()
+    });
+}
+
+fn bench_cmp_be(b: &mut Bencher) {
+    let values: Vec<BigEndian<u32>> = (0..1000u32)
+        .map(|v| v.wrapping_mul(2654435761).into())
+        .collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] < w[1]).count()
+    });
+}
+
+// The alternative to `bench_cmp_be`: ordering unsigned big-endian values by their bytes, without swapping them.
+fn bench_cmp_be_bytes(b: &mut Bencher) {
+    let values: Vec<BigEndian<u32>> = (0..1000u32)
+        .map(|v| v.wrapping_mul(2654435761).into())
+        .collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values
+            .windows(2)
+            .filter(|w| w[0].to_bits().to_ne_bytes() < w[1].to_bits().to_ne_bytes())
+            .count()
+    });
+}
+
+fn bench_cmp_le(b: &mut Bencher) {
+    let values: Vec<LittleEndian<u32>> = (0..1000u32)
+        .map(|v| v.wrapping_mul(2654435761).into())
+        .collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] < w[1]).count()
+    });
+}
+
+fn bench_cmp_ne(b: &mut Bencher) {
+    let values: Vec<u32> = (0..1000u32).map(|v| v.wrapping_mul(2654435761)).collect();
+    b.iter(|| {
+        let values = black_box(&values);
+        values.windows(2).filter(|w| w[0] < w[1]).count()
+    });
+}
+
+fn bench_hash_be(b: &mut Bencher) {
+    let values: Vec<BigEndian<u64>> = (0..1000u64).map(|v| v.into()).collect();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        black_box(&values).hash(&mut hasher);
+        hasher.finish()
+    });
+}
+
+fn bench_hash_ne(b: &mut Bencher) {
+    let values: Vec<u64> = (0..1000u64).collect();
+    b.iter(|| {
+        let mut hasher = DefaultHasher::new();
+        black_box(&values).hash(&mut hasher);
+        hasher.finish()
+    });
+}
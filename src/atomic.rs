@@ -0,0 +1,471 @@
+//! Atomic integers stored in a fixed byte order, for counters in shared memory or mmapped files that must be portable
+//! between hosts.
+//!
+//! The types are `#[repr(transparent)]` over the `core::sync::atomic` types, so they have the same size as e.g.
+//! `u32be` and can be placed in `#[repr(C)]` wire structs.  Note that the atomic types may be more strictly aligned
+//! than the plain integers on some targets.
+//!
+//! ```rust
+//! use core::sync::atomic::Ordering;
+//! use simple_endian_wrapper::*;
+//!
+//! #[repr(C)]
+//! struct Ring {
+//!     head: AtomicU32be,
+//!     tail: AtomicU32be,
+//! }
+//!
+//! let ring = Ring { head: AtomicU32be::new(0), tail: AtomicU32be::new(0) };
+//! ring.head.fetch_add(3, Ordering::AcqRel);
+//! assert_eq!(ring.head.load(Ordering::Acquire), 3);
+//! ```
+//!
+//! Loads, stores, swaps, compare-exchanges and the bitwise operations only swap the bytes of their operands.
+//! `fetch_add` and `fetch_sub` use a compare-exchange loop unless the byte order matches the host.
+
+#[allow(unused_imports)]
+use core::fmt::{Debug, Formatter};
+use core::sync::atomic::Ordering;
+#[allow(unused_imports)]
+use core::sync::atomic::{
+    AtomicI16, AtomicI32, AtomicI64, AtomicIsize, AtomicU16, AtomicU32, AtomicU64, AtomicUsize,
+};
+
+#[allow(unused_imports)]
+use super::*;
+
+/// The strongest ordering that is valid for the load of a read-modify-write operation with `order`.
+#[allow(dead_code)]
+fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+#[allow(unused_macros)]
+macro_rules! add_atomic {
+    ($atomic_ty:ident, $inner_ty:ty, $wrap_ty:ident, $value_ty:ty, $to:ident, $from:ident, $endian:literal) => {
+        #[doc = concat!("An atomic `", stringify!($value_ty), "` stored in ", $endian, " endian.")]
+        #[repr(transparent)]
+        #[derive(Default)]
+        pub struct $atomic_ty($inner_ty);
+
+        impl $atomic_ty {
+            /// Creates a new atomic integer.
+            pub const fn new(v: $value_ty) -> Self {
+                Self(<$inner_ty>::new(v.$to()))
+            }
+
+            /// Gets an atomic view of an endian field, e.g. in a mmapped struct.
+            ///
+            /// # Safety
+            ///
+            #[doc = concat!("The same as for `", stringify!($inner_ty), "::from_ptr`: `ptr` must be valid and aligned for the atomic type for `'a`, and must only be accessed atomically during that time.")]
+            pub unsafe fn from_ptr<'a>(ptr: *mut $wrap_ty<$value_ty>) -> &'a Self {
+                // Safety: Self is repr(transparent) over the atomic type, the rest is up to the caller.
+                unsafe { &*(<$inner_ty>::from_ptr(ptr as *mut $value_ty) as *const $inner_ty as *const Self) }
+            }
+
+            /// Returns a mutable reference to the underlying value.  This is safe because the mutable reference
+            /// guarantees that no other threads are concurrently accessing the value.
+            pub fn get_mut(&mut self) -> &mut $wrap_ty<$value_ty> {
+                // Safety: the wrapper is repr(transparent) over the integer, which has the same layout as the atomic.
+                unsafe { &mut *(self.0.as_ptr() as *mut $wrap_ty<$value_ty>) }
+            }
+
+            /// Consumes the atomic and returns the contained value.
+            pub fn into_inner(self) -> $wrap_ty<$value_ty> {
+                $wrap_ty::from_bits(self.0.into_inner())
+            }
+
+            /// Loads the native value.
+            pub fn load(&self, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.load(order))
+            }
+
+            /// Stores a native value.
+            pub fn store(&self, v: $value_ty, order: Ordering) {
+                self.0.store(v.$to(), order)
+            }
+
+            /// Stores a native value, returning the previous value.
+            pub fn swap(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.swap(v.$to(), order))
+            }
+
+            /// Stores `new` if the current value is `current`, see `compare_exchange` on the core atomics.
+            pub fn compare_exchange(
+                &self,
+                current: $value_ty,
+                new: $value_ty,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$value_ty, $value_ty> {
+                self.0
+                    .compare_exchange(current.$to(), new.$to(), success, failure)
+                    .map(<$value_ty>::$from)
+                    .map_err(<$value_ty>::$from)
+            }
+
+            /// Like `compare_exchange`, but may fail spuriously.
+            pub fn compare_exchange_weak(
+                &self,
+                current: $value_ty,
+                new: $value_ty,
+                success: Ordering,
+                failure: Ordering,
+            ) -> Result<$value_ty, $value_ty> {
+                self.0
+                    .compare_exchange_weak(current.$to(), new.$to(), success, failure)
+                    .map(<$value_ty>::$from)
+                    .map_err(<$value_ty>::$from)
+            }
+
+            /// Applies `f` to the native value until it succeeds, see `fetch_update` on the core atomics.
+            pub fn fetch_update<F>(
+                &self,
+                set_order: Ordering,
+                fetch_order: Ordering,
+                mut f: F,
+            ) -> Result<$value_ty, $value_ty>
+            where
+                F: FnMut($value_ty) -> Option<$value_ty>,
+            {
+                self.0
+                    .fetch_update(set_order, fetch_order, |bits| {
+                        f(<$value_ty>::$from(bits)).map(<$value_ty>::$to)
+                    })
+                    .map(<$value_ty>::$from)
+                    .map_err(<$value_ty>::$from)
+            }
+
+            /// Adds to the value, wrapping around on overflow, and returns the previous value.
+            pub fn fetch_add(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                if cfg!(target_endian = $endian) {
+                    self.0.fetch_add(v, order)
+                } else {
+                    self.fetch_update(order, load_ordering(order), |x| Some(x.wrapping_add(v)))
+                        .unwrap_or_else(|x| x)
+                }
+            }
+
+            /// Subtracts from the value, wrapping around on overflow, and returns the previous value.
+            pub fn fetch_sub(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                if cfg!(target_endian = $endian) {
+                    self.0.fetch_sub(v, order)
+                } else {
+                    self.fetch_update(order, load_ordering(order), |x| Some(x.wrapping_sub(v)))
+                        .unwrap_or_else(|x| x)
+                }
+            }
+
+            /// Bitwise "and" with the value, returning the previous value.
+            pub fn fetch_and(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.fetch_and(v.$to(), order))
+            }
+
+            /// Bitwise "nand" with the value, returning the previous value.
+            pub fn fetch_nand(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.fetch_nand(v.$to(), order))
+            }
+
+            /// Bitwise "or" with the value, returning the previous value.
+            pub fn fetch_or(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.fetch_or(v.$to(), order))
+            }
+
+            /// Bitwise "xor" with the value, returning the previous value.
+            pub fn fetch_xor(&self, v: $value_ty, order: Ordering) -> $value_ty {
+                <$value_ty>::$from(self.0.fetch_xor(v.$to(), order))
+            }
+        }
+
+        impl From<$value_ty> for $atomic_ty {
+            fn from(v: $value_ty) -> Self {
+                Self::new(v)
+            }
+        }
+
+        impl From<$wrap_ty<$value_ty>> for $atomic_ty {
+            fn from(v: $wrap_ty<$value_ty>) -> Self {
+                Self(<$inner_ty>::new(v.to_bits()))
+            }
+        }
+
+        impl Debug for $atomic_ty {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.load(Ordering::Relaxed), f)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "big_endian")]
+mod be {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(target_has_atomic = "16")]
+    add_atomic!(
+        AtomicU16be,
+        AtomicU16,
+        BigEndian,
+        u16,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "16")]
+    add_atomic!(
+        AtomicI16be,
+        AtomicI16,
+        BigEndian,
+        i16,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "32")]
+    add_atomic!(
+        AtomicU32be,
+        AtomicU32,
+        BigEndian,
+        u32,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "32")]
+    add_atomic!(
+        AtomicI32be,
+        AtomicI32,
+        BigEndian,
+        i32,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "64")]
+    add_atomic!(
+        AtomicU64be,
+        AtomicU64,
+        BigEndian,
+        u64,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "64")]
+    add_atomic!(
+        AtomicI64be,
+        AtomicI64,
+        BigEndian,
+        i64,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "ptr")]
+    add_atomic!(
+        AtomicUsizebe,
+        AtomicUsize,
+        BigEndian,
+        usize,
+        to_be,
+        from_be,
+        "big"
+    );
+    #[cfg(target_has_atomic = "ptr")]
+    add_atomic!(
+        AtomicIsizebe,
+        AtomicIsize,
+        BigEndian,
+        isize,
+        to_be,
+        from_be,
+        "big"
+    );
+}
+#[cfg(feature = "big_endian")]
+pub use be::*;
+
+#[cfg(feature = "little_endian")]
+mod le {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[cfg(target_has_atomic = "16")]
+    add_atomic!(
+        AtomicU16le,
+        AtomicU16,
+        LittleEndian,
+        u16,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "16")]
+    add_atomic!(
+        AtomicI16le,
+        AtomicI16,
+        LittleEndian,
+        i16,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "32")]
+    add_atomic!(
+        AtomicU32le,
+        AtomicU32,
+        LittleEndian,
+        u32,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "32")]
+    add_atomic!(
+        AtomicI32le,
+        AtomicI32,
+        LittleEndian,
+        i32,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "64")]
+    add_atomic!(
+        AtomicU64le,
+        AtomicU64,
+        LittleEndian,
+        u64,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "64")]
+    add_atomic!(
+        AtomicI64le,
+        AtomicI64,
+        LittleEndian,
+        i64,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "ptr")]
+    add_atomic!(
+        AtomicUsizele,
+        AtomicUsize,
+        LittleEndian,
+        usize,
+        to_le,
+        from_le,
+        "little"
+    );
+    #[cfg(target_has_atomic = "ptr")]
+    add_atomic!(
+        AtomicIsizele,
+        AtomicIsize,
+        LittleEndian,
+        isize,
+        to_le,
+        from_le,
+        "little"
+    );
+}
+#[cfg(feature = "little_endian")]
+pub use le::*;
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::mem::{align_of, size_of};
+    use core::sync::atomic::Ordering;
+
+    #[test]
+    fn load_store() {
+        let a = AtomicU32be::new(0x1234_5678);
+        assert_eq!(a.load(Ordering::Relaxed), 0x1234_5678);
+        a.store(7, Ordering::Relaxed);
+        assert_eq!(a.swap(9, Ordering::Relaxed), 7);
+        assert_eq!(a.into_inner(), u32be::from(9));
+        assert_eq!(size_of::<AtomicU64le>(), size_of::<u64le>());
+    }
+
+    #[test]
+    fn stored_in_declared_endian() {
+        let mut be = AtomicU16be::new(0x0102);
+        let mut le = AtomicI32le::new(-2);
+        assert_eq!(be.get_mut().to_bits(), 0x0102u16.to_be());
+        assert_eq!(le.get_mut().to_bits(), (-2i32).to_le());
+        *be.get_mut() = 0x0a0b.into();
+        assert_eq!(be.load(Ordering::Relaxed), 0x0a0b);
+    }
+
+    #[test]
+    fn compare_exchange() {
+        let a = AtomicI64be::new(5);
+        assert_eq!(
+            a.compare_exchange(4, 6, Ordering::AcqRel, Ordering::Acquire),
+            Err(5)
+        );
+        assert_eq!(
+            a.compare_exchange(5, 6, Ordering::AcqRel, Ordering::Acquire),
+            Ok(5)
+        );
+        assert_eq!(
+            a.fetch_update(Ordering::AcqRel, Ordering::Acquire, |v| Some(v * 10)),
+            Ok(6)
+        );
+        assert_eq!(a.load(Ordering::Relaxed), 60);
+    }
+
+    #[test]
+    fn fetch_ops() {
+        let a = AtomicU32le::new(u32::MAX);
+        assert_eq!(a.fetch_add(2, Ordering::SeqCst), u32::MAX);
+        assert_eq!(a.fetch_sub(3, Ordering::SeqCst), 1);
+        let b = AtomicU16be::new(0xff00);
+        assert_eq!(b.fetch_and(0x0ff0, Ordering::SeqCst), 0xff00);
+        assert_eq!(b.fetch_or(0x000f, Ordering::SeqCst), 0x0f00);
+        assert_eq!(b.fetch_xor(0xffff, Ordering::SeqCst), 0x0f0f);
+        assert_eq!(b.load(Ordering::SeqCst), 0xf0f0);
+    }
+
+    #[test]
+    fn concurrent_fetch_add() {
+        let counter = AtomicU64be::new(0);
+        std::thread::scope(|s| {
+            for _ in 0..4 {
+                s.spawn(|| {
+                    for _ in 0..1000 {
+                        counter.fetch_add(1, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+        assert_eq!(counter.load(Ordering::SeqCst), 4000);
+    }
+
+    #[test]
+    fn from_ptr() {
+        #[repr(C)]
+        struct Header {
+            magic: u32be,
+            count: u32be,
+        }
+        let mut header = Header {
+            magic: 0xfeedface.into(),
+            count: 1.into(),
+        };
+        assert!(align_of::<AtomicU32be>() <= align_of::<Header>());
+        let count = unsafe { AtomicU32be::from_ptr(&mut header.count) };
+        count.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(header.count, 2);
+        assert_eq!(header.magic, 0xfeedface);
+    }
+}
@@ -0,0 +1,247 @@
+//! Bitfields over endian wrappers, for protocol headers and device registers that pack several fields into one word.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//!
+//! bitfield! {
+//!     /// The first byte of an IPv4 header.
+//!     #[derive(Debug, PartialEq)]
+//!     pub struct VersionIhl(BigEndian<u8>);
+//!     msb0;
+//!     pub version, set_version: u8 @ 0..4;
+//!     pub ihl, set_ihl: u8 @ 4..8;
+//! }
+//!
+//! bitfield! {
+//!     /// Data offset and flags of a TCP header.
+//!     pub struct TcpFlags(u16be);
+//!     lsb0;
+//!     pub data_offset, set_data_offset: u8 @ 12..16;
+//!     pub syn, set_syn: bool @ 1;
+//!     pub ack, set_ack: bool @ 4;
+//! }
+//!
+//! let mut first = VersionIhl::default();
+//! first.set_version(4);
+//! first.set_ihl(5);
+//! assert_eq!(first.0, 0x45);
+//!
+//! let mut flags = TcpFlags(0x5002.into());
+//! assert!(flags.syn() && !flags.ack());
+//! flags.set_ack(true);
+//! assert_eq!(flags.data_offset(), 5);
+//! assert_eq!(flags.0, 0x5012);
+//! ```
+//!
+//! The storage must be a wrapper of an unsigned integer.  Bits are numbered either from the most significant bit
+//! (`msb0`) or from the least significant bit (`lsb0`) of the native value, and ranges are half-open.  Fields are read
+//! and written as unsigned values, and bits of a value that don't fit into its field are ignored.  The type of a
+//! field must be unsigned and wide enough for its range, which is checked at compile time:
+//!
+//! ```compile_fail
+//! use simple_endian_wrapper::*;
+//!
+//! bitfield! {
+//!     pub struct Fragment(u16be);
+//!     lsb0;
+//!     pub offset, set_offset: u8 @ 0..13;
+//! }
+//! ```
+//!
+//! Masks are converted to the stored byte order at compile time, so flags are read and written without any
+//! conversion, and setting a wider field only converts the new value of the field.
+
+/// Returns how far a field has to be shifted from the least significant bit.  Panics at compile time for invalid
+/// ranges.
+#[doc(hidden)]
+pub const fn __bitfield_shift(msb0: bool, bits: u32, start: u32, end: u32) -> u32 {
+    assert!(start < end && end <= bits, "bitfield range out of bounds");
+    if msb0 {
+        bits - end
+    } else {
+        start
+    }
+}
+
+/// Declares a struct over an endian wrapper with getters and setters for ranges of bits, see the module docs.
+#[macro_export]
+macro_rules! bitfield {
+    (
+        $(#[$attr:meta])*
+        $vis:vis struct $name:ident($($storage:tt)+);
+        $order:ident;
+        $($fields:tt)*
+    ) => {
+        $(#[$attr])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Default)]
+        $vis struct $name(pub $($storage)+);
+
+        impl $name {
+            $crate::bitfield!(@fields ($($storage)+), $crate::bitfield!(@msb0 $order); $($fields)*);
+        }
+
+        impl From<$($storage)+> for $name {
+            fn from(v: $($storage)+) -> Self {
+                Self(v)
+            }
+        }
+
+        impl From<$name> for $($storage)+ {
+            fn from(v: $name) -> Self {
+                v.0
+            }
+        }
+    };
+
+    (@msb0 msb0) => { true };
+    (@msb0 lsb0) => { false };
+
+    (@fields ($($storage:tt)+), $msb0:expr;) => {};
+    (
+        @fields ($($storage:tt)+), $msb0:expr;
+        $(#[$fattr:meta])*
+        $fvis:vis $getter:ident, $setter:ident: bool @ $bit:literal;
+        $($rest:tt)*
+    ) => {
+        $(#[$fattr])*
+        $fvis fn $getter(&self) -> bool {
+            self.0.to_bits() & $crate::bitfield!(@stored_mask ($($storage)+), $msb0, $bit, $bit + 1) != 0
+        }
+
+        $(#[$fattr])*
+        $fvis fn $setter(&mut self, value: bool) {
+            const MASK: <$($storage)+ as $crate::__BitfieldStorage>::Bits =
+                $crate::bitfield!(@stored_mask ($($storage)+), $msb0, $bit, $bit + 1);
+            let bits = self.0.to_bits();
+            self.0 = <$($storage)+>::from_bits(if value { bits | MASK } else { bits & !MASK });
+        }
+
+        $crate::bitfield!(@fields ($($storage)+), $msb0; $($rest)*);
+    };
+    (
+        @fields ($($storage:tt)+), $msb0:expr;
+        $(#[$fattr:meta])*
+        $fvis:vis $getter:ident, $setter:ident: $ty:ident @ $start:literal..$end:literal;
+        $($rest:tt)*
+    ) => {
+        $(#[$fattr])*
+        $fvis fn $getter(&self) -> $ty {
+            const _: () = assert!(
+                <$ty>::MIN == 0 && <$ty>::BITS >= $end - $start,
+                "bitfield type must be unsigned and hold the whole range"
+            );
+            const SHIFT: u32 = $crate::__bitfield_shift($msb0, <$($storage)+ as $crate::__BitfieldStorage>::BITS, $start, $end);
+            const MASK: <$($storage)+ as $crate::__BitfieldStorage>::Bits =
+                $crate::bitfield!(@stored_mask ($($storage)+), $msb0, $start, $end);
+            (<$($storage)+>::from_bits(self.0.to_bits() & MASK).get() >> SHIFT) as $ty
+        }
+
+        $(#[$fattr])*
+        $fvis fn $setter(&mut self, value: $ty) {
+            const SHIFT: u32 = $crate::__bitfield_shift($msb0, <$($storage)+ as $crate::__BitfieldStorage>::BITS, $start, $end);
+            const MASK: <$($storage)+ as $crate::__BitfieldStorage>::Bits =
+                $crate::bitfield!(@stored_mask ($($storage)+), $msb0, $start, $end);
+            let native_mask = <$($storage)+>::from_bits(MASK).get();
+            let field = <$($storage)+>::new(((value as <$($storage)+ as $crate::__BitfieldStorage>::Bits) << SHIFT) & native_mask);
+            self.0 = <$($storage)+>::from_bits((self.0.to_bits() & !MASK) | field.to_bits());
+        }
+
+        $crate::bitfield!(@fields ($($storage)+), $msb0; $($rest)*);
+    };
+
+    (@stored_mask ($($storage:tt)+), $msb0:expr, $start:expr, $end:expr) => {{
+        const BITS: u32 = <$($storage)+ as $crate::__BitfieldStorage>::BITS;
+        const SHIFT: u32 = $crate::__bitfield_shift($msb0, BITS, $start, $end);
+        const MASK: <$($storage)+ as $crate::__BitfieldStorage>::Bits =
+            <$($storage)+>::new(<$($storage)+ as $crate::__BitfieldStorage>::ONES >> (BITS - ($end - $start)) << SHIFT)
+                .to_bits();
+        MASK
+    }};
+}
+
+/// The unsigned integer wrappers that can be used as storage for `bitfield!`.
+#[doc(hidden)]
+pub trait __BitfieldStorage {
+    type Bits;
+    const BITS: u32;
+    const ONES: Self::Bits;
+}
+
+#[allow(unused_macros)]
+macro_rules! add_bitfield_storage {
+    ($($int_ty:ty),+) => {
+        $(
+            #[cfg(feature = "big_endian")]
+            impl __BitfieldStorage for crate::BigEndian<$int_ty> {
+                type Bits = $int_ty;
+                const BITS: u32 = <$int_ty>::BITS;
+                const ONES: $int_ty = <$int_ty>::MAX;
+            }
+            #[cfg(feature = "little_endian")]
+            impl __BitfieldStorage for crate::LittleEndian<$int_ty> {
+                type Bits = $int_ty;
+                const BITS: u32 = <$int_ty>::BITS;
+                const ONES: $int_ty = <$int_ty>::MAX;
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "byte_impls")]
+add_bitfield_storage!(u8);
+#[cfg(feature = "integer_impls")]
+add_bitfield_storage!(u16, u32, u64, u128, usize);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    bitfield! {
+        struct Register(u32le);
+        lsb0;
+        enable, set_enable: bool @ 0;
+        mode, set_mode: u8 @ 1..4;
+        count, set_count: u16 @ 16..32;
+    }
+
+    bitfield! {
+        #[derive(Debug, PartialEq)]
+        struct Ipv4Flags(u16be);
+        msb0;
+        /// Don't fragment.
+        df, set_df: bool @ 1;
+        /// More fragments.
+        mf, set_mf: bool @ 2;
+        fragment_offset, set_fragment_offset: u16 @ 3..16;
+    }
+
+    #[test]
+    fn lsb0() {
+        let mut reg = Register::default();
+        reg.set_enable(true);
+        reg.set_mode(0b101);
+        reg.set_count(0xbeef);
+        assert!(reg.enable());
+        assert_eq!(reg.mode(), 0b101);
+        assert_eq!(reg.count(), 0xbeef);
+        assert_eq!(reg.0, 0xbeef_000b);
+        reg.set_mode(0xff);
+        assert_eq!(reg.0, 0xbeef_000f);
+        reg.set_enable(false);
+        assert_eq!(u32le::from(reg), 0xbeef_000e);
+    }
+
+    #[test]
+    fn msb0() {
+        let mut flags = Ipv4Flags::from(u16be::from(0x4000));
+        assert!(flags.df());
+        assert!(!flags.mf());
+        flags.set_mf(true);
+        flags.set_fragment_offset(0x1234);
+        assert_eq!(flags.fragment_offset(), 0x1234);
+        assert_eq!(flags.0.to_bits(), 0x7234u16.to_be());
+        flags.set_df(false);
+        assert_eq!(flags, Ipv4Flags(0x3234.into()));
+    }
+}
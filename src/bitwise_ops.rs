@@ -7,7 +7,7 @@
 //! let mask = BigEndian::from(0xf0f0f);
 //! a &= mask;
 //! a |= BigEndian::from(0xfff0000) | mask;
-//! a ^= 0x5555555.into();
+//! a ^= BigEndian::from(0x5555555);
 //! ```
 
 use core::{
@@ -64,6 +64,58 @@ macro_rules! add_bitwise_ops {
                 Self(!self.0, PhantomData)
             }
         }
+        impl Not for &$wrap_ty {
+            type Output = $wrap_ty;
+
+            fn not(self) -> Self::Output {
+                !*self
+            }
+        }
+        forward_ref_binop!($wrap_ty, BitAnd, bitand, BitAndAssign, bitand_assign);
+        forward_ref_binop!($wrap_ty, BitXor, bitxor, BitXorAssign, bitxor_assign);
+        forward_ref_binop!($wrap_ty, BitOr, bitor, BitOrAssign, bitor_assign);
+    };
+}
+
+/// Bitwise operations between operands of different endianness.  The right operand is converted to the endianness of
+/// the left one, which is also the endianness of the result.
+#[cfg(feature = "both_endian")]
+#[allow(unused_macros)]
+macro_rules! add_mixed_bitwise_ops {
+    ($wrap_ty:ty, $other_ty:ty) => {
+        impl BitAnd<$other_ty> for $wrap_ty {
+            type Output = Self;
+            fn bitand(self, rhs: $other_ty) -> Self::Output {
+                self & Self::from(rhs)
+            }
+        }
+        impl BitAndAssign<$other_ty> for $wrap_ty {
+            fn bitand_assign(&mut self, rhs: $other_ty) {
+                *self = *self & rhs
+            }
+        }
+        impl BitXor<$other_ty> for $wrap_ty {
+            type Output = Self;
+            fn bitxor(self, rhs: $other_ty) -> Self::Output {
+                self ^ Self::from(rhs)
+            }
+        }
+        impl BitXorAssign<$other_ty> for $wrap_ty {
+            fn bitxor_assign(&mut self, rhs: $other_ty) {
+                *self = *self ^ rhs
+            }
+        }
+        impl BitOr<$other_ty> for $wrap_ty {
+            type Output = Self;
+            fn bitor(self, rhs: $other_ty) -> Self::Output {
+                self | Self::from(rhs)
+            }
+        }
+        impl BitOrAssign<$other_ty> for $wrap_ty {
+            fn bitor_assign(&mut self, rhs: $other_ty) {
+                *self = *self | rhs
+            }
+        }
     };
 }
 
@@ -84,6 +136,17 @@ mod bitwise_byte_ops {
         add_bitwise_ops!(LittleEndian<u8>);
         add_bitwise_ops!(LittleEndian<i8>);
     }
+
+    #[cfg(feature = "both_endian")]
+    mod mixed {
+        use super::*;
+        add_mixed_bitwise_ops!(BigEndian<bool>, LittleEndian<bool>);
+        add_mixed_bitwise_ops!(BigEndian<u8>, LittleEndian<u8>);
+        add_mixed_bitwise_ops!(BigEndian<i8>, LittleEndian<i8>);
+        add_mixed_bitwise_ops!(LittleEndian<bool>, BigEndian<bool>);
+        add_mixed_bitwise_ops!(LittleEndian<u8>, BigEndian<u8>);
+        add_mixed_bitwise_ops!(LittleEndian<i8>, BigEndian<i8>);
+    }
 }
 
 #[cfg(feature = "integer_impls")]
@@ -118,6 +181,31 @@ mod bitwise_integer_ops {
         add_bitwise_ops!(LittleEndian<usize>);
         add_bitwise_ops!(LittleEndian<isize>);
     }
+
+    #[cfg(feature = "both_endian")]
+    mod mixed {
+        use super::*;
+        add_mixed_bitwise_ops!(BigEndian<u16>, LittleEndian<u16>);
+        add_mixed_bitwise_ops!(BigEndian<i16>, LittleEndian<i16>);
+        add_mixed_bitwise_ops!(BigEndian<u32>, LittleEndian<u32>);
+        add_mixed_bitwise_ops!(BigEndian<i32>, LittleEndian<i32>);
+        add_mixed_bitwise_ops!(BigEndian<u64>, LittleEndian<u64>);
+        add_mixed_bitwise_ops!(BigEndian<i64>, LittleEndian<i64>);
+        add_mixed_bitwise_ops!(BigEndian<u128>, LittleEndian<u128>);
+        add_mixed_bitwise_ops!(BigEndian<i128>, LittleEndian<i128>);
+        add_mixed_bitwise_ops!(BigEndian<usize>, LittleEndian<usize>);
+        add_mixed_bitwise_ops!(BigEndian<isize>, LittleEndian<isize>);
+        add_mixed_bitwise_ops!(LittleEndian<u16>, BigEndian<u16>);
+        add_mixed_bitwise_ops!(LittleEndian<i16>, BigEndian<i16>);
+        add_mixed_bitwise_ops!(LittleEndian<u32>, BigEndian<u32>);
+        add_mixed_bitwise_ops!(LittleEndian<i32>, BigEndian<i32>);
+        add_mixed_bitwise_ops!(LittleEndian<u64>, BigEndian<u64>);
+        add_mixed_bitwise_ops!(LittleEndian<i64>, BigEndian<i64>);
+        add_mixed_bitwise_ops!(LittleEndian<u128>, BigEndian<u128>);
+        add_mixed_bitwise_ops!(LittleEndian<i128>, BigEndian<i128>);
+        add_mixed_bitwise_ops!(LittleEndian<usize>, BigEndian<usize>);
+        add_mixed_bitwise_ops!(LittleEndian<isize>, BigEndian<isize>);
+    }
 }
 
 #[cfg(test)]
@@ -136,4 +224,27 @@ mod tests {
         let be1 = BigEndian::<u16>::from(0x0f0);
         assert_eq!(0xff0f, u16::from(!be1));
     }
+
+    #[test]
+    fn mixed_endian_test() {
+        let mut be = BigEndian::<u32>::from(0xff00ff00);
+        let le = LittleEndian::<u32>::from(0x0ff00ff0);
+        assert_eq!(0x0f000f00, u32::from(be & le));
+        assert_eq!(0xfff0fff0, u32::from(le | be));
+        be ^= le;
+        assert_eq!(0xf0f0f0f0, u32::from(be));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ref_ops_test() {
+        let mut a = BigEndian::<u16>::from(0xff00);
+        let b = BigEndian::<u16>::from(0x0ff0);
+        assert_eq!(0x0f00, u16::from(&a & b));
+        assert_eq!(0xfff0, u16::from(a | &b));
+        assert_eq!(0xf0f0, u16::from(&a ^ &b));
+        assert_eq!(0x00ff, u16::from(!&a));
+        a &= &b;
+        assert_eq!(0x0f00, u16::from(a));
+    }
 }
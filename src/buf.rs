@@ -0,0 +1,192 @@
+//! Extension traits for the `Buf` and `BufMut` traits of the `bytes` crate, which keep the byte order in the type.
+//!
+//! ```rust
+//! use bytes::{Bytes, BytesMut};
+//! use simple_endian_wrapper::*;
+//!
+//! let mut out = BytesMut::new();
+//! out.put_endian(u32be::from(0xcafe));
+//! out.put_endian_slice(&[u16le::from(1), u16le::from(2)]);
+//!
+//! let mut frame = out.freeze();
+//! let magic = frame.get_endian::<u32be>();
+//! assert_eq!(magic, 0xcafe);
+//! assert_eq!(frame.try_get_endian::<u64le>().unwrap_err().available, 4);
+//!
+//! // Borrow the rest in place, which works if the bytes are aligned for the type.
+//! let words = BytesSlice::<u16le>::new(frame).unwrap_or_else(|b| BytesSlice::copy_from(&b));
+//! assert_eq!(*words, [u16le::from(1), u16le::from(2)]);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Deref;
+
+use ::bytes::{Buf, BufMut, Bytes, TryGetError};
+
+use super::*;
+
+/// Reads endian values from any `Buf`.
+pub trait BufEndianExt: Buf {
+    /// Reads one value or wire struct, e.g. `buf.get_endian::<u32be>()`.  Panics if there aren't enough bytes left,
+    /// like `get_u32()`.
+    fn get_endian<T: EndianBytes>(&mut self) -> T {
+        let mut v = T::zeroed();
+        self.copy_to_slice(v.as_bytes_mut());
+        v
+    }
+
+    /// Reads one value, or returns an error without advancing if there aren't enough bytes left.
+    fn try_get_endian<T: EndianBytes>(&mut self) -> Result<T, TryGetError> {
+        let mut v = T::zeroed();
+        self.try_copy_to_slice(v.as_bytes_mut())?;
+        Ok(v)
+    }
+
+    /// Fills `values` from the buffer.  Panics if there aren't enough bytes left.
+    fn get_endian_into<T: EndianBytes>(&mut self, values: &mut [T]) {
+        self.copy_to_slice(T::slice_as_bytes_mut(values))
+    }
+}
+
+impl<B: Buf + ?Sized> BufEndianExt for B {}
+
+/// Writes endian values to any `BufMut`.
+pub trait BufMutEndianExt: BufMut {
+    /// Writes one value or wire struct, e.g. `buf.put_endian(u16le::from(7))`.  Panics if there isn't enough space,
+    /// like `put_u16_le()`.
+    fn put_endian<T: EndianBytes>(&mut self, v: T) {
+        self.put_slice(v.as_bytes())
+    }
+
+    /// Writes all `values`.
+    fn put_endian_slice<T: EndianBytes>(&mut self, values: &[T]) {
+        self.put_slice(T::slice_as_bytes(values))
+    }
+}
+
+impl<B: BufMut + ?Sized> BufMutEndianExt for B {}
+
+/// A `Bytes` viewed as a slice of wrappers or wire structs, without copying.
+#[derive(Clone)]
+pub struct BytesSlice<T> {
+    bytes: Bytes,
+    _marker: PhantomData<T>,
+}
+
+impl<T: EndianBytes> BytesSlice<T> {
+    /// Views `bytes` in place, or gives them back if they aren't aligned for `T` or their length isn't a multiple
+    /// of its size.
+    pub fn new(bytes: Bytes) -> Result<Self, Bytes> {
+        if T::SIZE == 0
+            || !bytes.len().is_multiple_of(T::SIZE)
+            || bytes.as_ptr().align_offset(core::mem::align_of::<T>()) != 0
+        {
+            return Err(bytes);
+        }
+        Ok(Self {
+            bytes,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Copies `bytes` into a new, aligned buffer.  Panics if their length isn't a multiple of the size of `T`.
+    pub fn copy_from(bytes: &[u8]) -> Self
+    where
+        T: Send + 'static,
+    {
+        assert!(
+            T::SIZE != 0 && bytes.len().is_multiple_of(T::SIZE),
+            "length is not a multiple of the size"
+        );
+        let mut values = alloc::vec![T::zeroed(); bytes.len() / T::SIZE];
+        T::slice_as_bytes_mut(&mut values).copy_from_slice(bytes);
+        Self::new(Bytes::from_owner(Owned(values))).unwrap_or_else(|_| unreachable!())
+    }
+
+    /// The underlying bytes.
+    pub fn into_bytes(self) -> Bytes {
+        self.bytes
+    }
+}
+
+impl<T: EndianBytes> Deref for BytesSlice<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        let len = self.bytes.len() / T::SIZE;
+        // Safety: `new()` checked the alignment and the length, and every byte pattern is a valid `T`.
+        unsafe { core::slice::from_raw_parts(self.bytes.as_ptr() as *const T, len) }
+    }
+}
+
+impl<T: EndianBytes + fmt::Debug> fmt::Debug for BytesSlice<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&**self, f)
+    }
+}
+
+/// Keeps the values of `BytesSlice::copy_from()` alive, and aligned, inside `Bytes`.
+struct Owned<T>(alloc::vec::Vec<T>);
+
+impl<T: EndianBytes> AsRef<[u8]> for Owned<T> {
+    fn as_ref(&self) -> &[u8] {
+        T::slice_as_bytes(&self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use bytes::{Bytes, BytesMut};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Record {
+        id: u16be,
+        len: u16le,
+    }
+
+    unsafe impl EndianStruct for Record {}
+
+    #[test]
+    fn get_and_put() {
+        let mut out = BytesMut::new();
+        out.put_endian(i64be::from(-2));
+        out.put_endian(Record {
+            id: 1.into(),
+            len: 2.into(),
+        });
+        out.put_endian_slice(&[f32le::from(0.5); 2]);
+        assert_eq!(out.len(), 20);
+
+        let mut buf = out.freeze();
+        assert_eq!(buf.get_endian::<i64be>(), -2);
+        assert_eq!(buf.get_endian::<Record>().len, 2);
+        let mut floats = [f32le::from(0.0); 2];
+        buf.get_endian_into(&mut floats);
+        assert_eq!(floats, [f32le::from(0.5); 2]);
+        assert_eq!(buf.try_get_endian::<u16be>().unwrap_err().requested, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_past_end() {
+        Bytes::from_static(&[1]).get_endian::<u16le>();
+    }
+
+    #[test]
+    fn slices() {
+        let records = BytesSlice::<Record>::copy_from(&[0, 1, 2, 0, 0, 3, 4, 0]);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].id, 3);
+
+        let bytes = records.clone().into_bytes();
+        let view = BytesSlice::<Record>::new(bytes.clone()).unwrap();
+        assert_eq!(view.as_ptr() as *const u8, bytes.as_ptr());
+        assert_eq!(*view, *records);
+
+        assert!(BytesSlice::<Record>::new(bytes.slice(1..5)).is_err());
+        assert!(BytesSlice::<Record>::new(bytes.slice(..6)).is_err());
+    }
+}
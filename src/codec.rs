@@ -0,0 +1,291 @@
+//! A `tokio-util` codec for frames made of a fixed wire struct header, followed by a payload whose length is in the
+//! header.
+//!
+//! ```rust
+//! use bytes::{Bytes, BytesMut};
+//! use simple_endian_wrapper::*;
+//! use tokio_util::codec::{Decoder, Encoder};
+//!
+//! #[derive(Clone, Copy)]
+//! #[repr(C)]
+//! struct Header {
+//!     kind: u16be,
+//!     len: u16be,
+//! }
+//!
+//! // Safety: repr(C) with two `u16be` fields, see `EndianStruct`.
+//! unsafe impl EndianStruct for Header {}
+//!
+//! impl FrameHeader for Header {
+//!     fn payload_len(&self) -> usize {
+//!         self.len.to_native().into()
+//!     }
+//! }
+//!
+//! let mut codec = EndianFrameCodec::<Header>::with_max_frame_len(1024);
+//! let mut buf = BytesMut::new();
+//! let header = Header { kind: 7.into(), len: 5.into() };
+//! codec.encode(EndianFrame::new(header, Bytes::from_static(b"hello")), &mut buf)?;
+//! assert_eq!(buf[..], *b"\x00\x07\x00\x05hello");
+//!
+//! let frame = codec.decode(&mut buf)?.unwrap();
+//! assert_eq!(frame.header.kind, 7);
+//! assert_eq!(frame.payload, "hello");
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! Frames that are longer than the maximum, header included, fail with an `io::Error` of kind `InvalidData` that
+//! wraps `FrameTooLarge`.  Decoding reads the header before the payload has arrived, so the limit is enforced
+//! without buffering oversized frames.
+
+use core::fmt::{self, Display, Formatter};
+use core::marker::PhantomData;
+use std::io::{Error, ErrorKind};
+
+use ::bytes::{Buf, Bytes, BytesMut};
+use ::tokio_util::codec::{Decoder, Encoder};
+
+use super::*;
+
+/// A wire struct at the start of each frame, which knows the length of the payload that follows it.
+pub trait FrameHeader: EndianBytes {
+    /// The number of payload bytes after the header.
+    fn payload_len(&self) -> usize;
+}
+
+/// A decoded header and its payload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct EndianFrame<H> {
+    /// The header, as it was on the wire.
+    pub header: H,
+    /// The bytes after the header.
+    pub payload: Bytes,
+}
+
+impl<H> EndianFrame<H> {
+    /// Creates a frame from its parts.
+    pub fn new(header: H, payload: Bytes) -> Self {
+        Self { header, payload }
+    }
+}
+
+/// Returned, wrapped in an `io::Error`, for frames that are longer than the maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FrameTooLarge {
+    /// The length of the frame, header included.
+    pub len: usize,
+    /// The maximum length of the codec.
+    pub max: usize,
+}
+
+impl Display for FrameTooLarge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "frame of {} bytes is larger than the maximum of {}",
+            self.len, self.max
+        )
+    }
+}
+
+impl std::error::Error for FrameTooLarge {}
+
+/// Decodes and encodes `EndianFrame<H>`, see the module docs.
+#[derive(Debug, Clone, Copy)]
+pub struct EndianFrameCodec<H> {
+    max_frame_len: usize,
+    _marker: PhantomData<H>,
+}
+
+impl<H: FrameHeader> EndianFrameCodec<H> {
+    /// The default maximum frame length, 8 MiB.
+    pub const DEFAULT_MAX_FRAME_LEN: usize = 8 * 1024 * 1024;
+
+    /// Creates a codec with the default maximum frame length.
+    pub const fn new() -> Self {
+        Self::with_max_frame_len(Self::DEFAULT_MAX_FRAME_LEN)
+    }
+
+    /// Creates a codec that rejects frames longer than `max` bytes, header included.
+    pub const fn with_max_frame_len(max: usize) -> Self {
+        Self {
+            max_frame_len: max,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The maximum length of a frame, header included.
+    pub const fn max_frame_len(&self) -> usize {
+        self.max_frame_len
+    }
+
+    /// Changes the maximum length of a frame, e.g. after a handshake.
+    pub fn set_max_frame_len(&mut self, max: usize) {
+        self.max_frame_len = max;
+    }
+
+    /// The length of a frame with `payload_len` bytes of payload, or an error if it's too long.
+    fn frame_len(&self, payload_len: usize) -> Result<usize, Error> {
+        match H::SIZE.checked_add(payload_len) {
+            Some(len) if len <= self.max_frame_len => Ok(len),
+            len => Err(Error::new(
+                ErrorKind::InvalidData,
+                FrameTooLarge {
+                    len: len.unwrap_or(usize::MAX),
+                    max: self.max_frame_len,
+                },
+            )),
+        }
+    }
+}
+
+impl<H: FrameHeader> Default for EndianFrameCodec<H> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<H: FrameHeader> Decoder for EndianFrameCodec<H> {
+    type Item = EndianFrame<H>;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<Self::Item>, Error> {
+        let Some(header) = src.get(..H::SIZE).and_then(H::from_bytes) else {
+            src.reserve(H::SIZE - src.len());
+            return Ok(None);
+        };
+        let frame_len = self.frame_len(header.payload_len())?;
+        if src.len() < frame_len {
+            src.reserve(frame_len - src.len());
+            return Ok(None);
+        }
+        src.advance(H::SIZE);
+        let payload = src.split_to(frame_len - H::SIZE).freeze();
+        Ok(Some(EndianFrame::new(header, payload)))
+    }
+}
+
+impl<H: FrameHeader> Encoder<EndianFrame<H>> for EndianFrameCodec<H> {
+    type Error = Error;
+
+    /// Fails with `InvalidInput` if the length in the header doesn't match the payload.
+    fn encode(&mut self, frame: EndianFrame<H>, dst: &mut BytesMut) -> Result<(), Error> {
+        if frame.header.payload_len() != frame.payload.len() {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "payload length doesn't match the header",
+            ));
+        }
+        let frame_len = self.frame_len(frame.payload.len())?;
+        dst.reserve(frame_len);
+        dst.put_endian(frame.header);
+        dst.extend_from_slice(&frame.payload);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_util::block_on;
+    use crate::*;
+    use bytes::{Bytes, BytesMut};
+    use futures_util::{SinkExt, StreamExt};
+    use std::io::ErrorKind;
+    use tokio_util::codec::{Decoder, Encoder, FramedRead, FramedWrite};
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Header {
+        magic: u16be,
+        kind: BigEndian<u8>,
+        flags: BigEndian<u8>,
+        len: u32be,
+    }
+
+    unsafe impl EndianStruct for Header {}
+
+    impl FrameHeader for Header {
+        fn payload_len(&self) -> usize {
+            self.len.to_native() as usize
+        }
+    }
+
+    fn frame(kind: u8, payload: &'static [u8]) -> EndianFrame<Header> {
+        let header = Header {
+            magic: 0xbeef.into(),
+            kind: kind.into(),
+            flags: 0.into(),
+            len: (payload.len() as u32).into(),
+        };
+        EndianFrame::new(header, Bytes::from_static(payload))
+    }
+
+    #[test]
+    fn framed_duplex() {
+        let (client, server) = tokio::io::duplex(256);
+        let mut sink = FramedWrite::new(client, EndianFrameCodec::<Header>::new());
+        let mut stream = FramedRead::new(server, EndianFrameCodec::<Header>::new());
+        block_on(async {
+            sink.send(frame(1, b"first")).await.unwrap();
+            sink.send(frame(2, b"")).await.unwrap();
+            sink.send(frame(3, b"third")).await.unwrap();
+        });
+        drop(sink);
+        block_on(async {
+            assert_eq!(stream.next().await.unwrap().unwrap(), frame(1, b"first"));
+            assert_eq!(stream.next().await.unwrap().unwrap(), frame(2, b""));
+            assert_eq!(stream.next().await.unwrap().unwrap(), frame(3, b"third"));
+            assert!(stream.next().await.is_none());
+        });
+    }
+
+    #[test]
+    fn partial_frames() {
+        let mut codec = EndianFrameCodec::<Header>::new();
+        let mut encoded = BytesMut::new();
+        codec.encode(frame(1, b"payload"), &mut encoded).unwrap();
+
+        let mut buf = BytesMut::new();
+        for &byte in &encoded[..encoded.len() - 1] {
+            buf.extend_from_slice(&[byte]);
+            assert_eq!(codec.decode(&mut buf).unwrap(), None);
+        }
+        buf.extend_from_slice(&encoded[encoded.len() - 1..]);
+        assert_eq!(codec.decode(&mut buf).unwrap(), Some(frame(1, b"payload")));
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn max_frame_len() {
+        let mut codec = EndianFrameCodec::<Header>::new();
+        codec.set_max_frame_len(12);
+        assert_eq!(codec.max_frame_len(), 12);
+        let mut buf = BytesMut::new();
+        codec.encode(frame(1, b"1234"), &mut buf).unwrap();
+
+        let err = codec.encode(frame(1, b"12345"), &mut buf).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+
+        // Only the header of the oversized frame is needed to reject it.
+        let mut oversized = BytesMut::new();
+        EndianFrameCodec::<Header>::new()
+            .encode(frame(2, b"too long"), &mut oversized)
+            .unwrap();
+        oversized.truncate(8);
+        let err = codec.decode(&mut oversized).unwrap_err();
+        assert_eq!(
+            err.into_inner().unwrap().downcast_ref(),
+            Some(&FrameTooLarge { len: 16, max: 12 })
+        );
+    }
+
+    #[test]
+    fn header_mismatch() {
+        let mut bad = frame(1, b"abc");
+        bad.payload = Bytes::from_static(b"ab");
+        let err = EndianFrameCodec::new()
+            .encode(bad, &mut BytesMut::new())
+            .unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+}
@@ -7,7 +7,7 @@ use super::*;
 
 impl<V, B> PartialOrd for BigEndian<V, B>
 where
-    V: SpecificEndian<B> + PartialOrd,
+    V: EndianRepr<B> + PartialOrd,
     B: Copy,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -17,7 +17,7 @@ where
 
 impl<V, B> PartialOrd for LittleEndian<V, B>
 where
-    V: SpecificEndian<B> + PartialOrd,
+    V: EndianRepr<B> + PartialOrd,
     B: Copy,
 {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
@@ -25,9 +25,12 @@ where
     }
 }
 
+// Unsigned big-endian values could be ordered by comparing their bytes, but on little-endian hosts that is slower
+// than swapping both operands (compare `bench_cmp_be_bytes` with `bench_cmp_be`), so ordering always goes through the
+// native values.
 impl<V, B> Ord for BigEndian<V, B>
 where
-    V: SpecificEndian<B> + Ord,
+    V: EndianRepr<B> + Ord,
     B: Copy,
 {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -37,7 +40,7 @@ where
 
 impl<V, B> Ord for LittleEndian<V, B>
 where
-    V: SpecificEndian<B> + Ord,
+    V: EndianRepr<B> + Ord,
     B: Copy,
 {
     fn cmp(&self, other: &Self) -> Ordering {
@@ -45,6 +48,154 @@ where
     }
 }
 
+/// Compare a wrapper directly with a native value, e.g. `hdr.len == 5`.
+macro_rules! add_native_cmp_ops {
+    ($wrap_ty:ident) => {
+        impl<V, B> PartialEq<V> for $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B> + PartialEq,
+            B: Copy,
+        {
+            fn eq(&self, other: &V) -> bool {
+                self.to_native() == *other
+            }
+        }
+
+        impl<V, B> PartialOrd<V> for $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B> + PartialOrd,
+            B: Copy,
+        {
+            fn partial_cmp(&self, other: &V) -> Option<Ordering> {
+                self.to_native().partial_cmp(other)
+            }
+        }
+    };
+}
+
+add_native_cmp_ops!(BigEndian);
+add_native_cmp_ops!(LittleEndian);
+
+/// Compare values stored in different byte orders, e.g. `be < le`.
+#[cfg(feature = "both_endian")]
+macro_rules! add_cross_endian_cmp_ops {
+    ($wrap_ty:ident, $other_ty:ident) => {
+        impl<V, B> PartialEq<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B> + PartialEq,
+            B: Copy,
+        {
+            fn eq(&self, other: &$other_ty<V, B>) -> bool {
+                self.to_native() == other.to_native()
+            }
+        }
+
+        impl<V, B> PartialOrd<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B> + PartialOrd,
+            B: Copy,
+        {
+            fn partial_cmp(&self, other: &$other_ty<V, B>) -> Option<Ordering> {
+                self.to_native().partial_cmp(&other.to_native())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "both_endian")]
+add_cross_endian_cmp_ops!(BigEndian, LittleEndian);
+#[cfg(feature = "both_endian")]
+add_cross_endian_cmp_ops!(LittleEndian, BigEndian);
+
+/// Compare a native value with a wrapper, e.g. `5 == hdr.len`.  The orphan rule prevents a generic implementation, so
+/// this is implemented for the primitive types only.
+#[allow(unused_macros)]
+macro_rules! add_primitive_cmp_ops {
+    ($wrap_ty:ident, $value_ty:ty, $bit_ty:ty) => {
+        impl PartialEq<$wrap_ty<$value_ty, $bit_ty>> for $value_ty {
+            fn eq(&self, other: &$wrap_ty<$value_ty, $bit_ty>) -> bool {
+                *self == other.to_native()
+            }
+        }
+
+        impl PartialOrd<$wrap_ty<$value_ty, $bit_ty>> for $value_ty {
+            fn partial_cmp(&self, other: &$wrap_ty<$value_ty, $bit_ty>) -> Option<Ordering> {
+                self.partial_cmp(&other.to_native())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "big_endian")]
+mod be {
+    #[allow(unused_imports)]
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_primitive_cmp_ops!(BigEndian, bool, bool);
+        add_primitive_cmp_ops!(BigEndian, u8, u8);
+        add_primitive_cmp_ops!(BigEndian, i8, i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_primitive_cmp_ops!(BigEndian, u16, u16);
+        add_primitive_cmp_ops!(BigEndian, i16, i16);
+        add_primitive_cmp_ops!(BigEndian, u32, u32);
+        add_primitive_cmp_ops!(BigEndian, i32, i32);
+        add_primitive_cmp_ops!(BigEndian, u64, u64);
+        add_primitive_cmp_ops!(BigEndian, i64, i64);
+        add_primitive_cmp_ops!(BigEndian, u128, u128);
+        add_primitive_cmp_ops!(BigEndian, i128, i128);
+        add_primitive_cmp_ops!(BigEndian, usize, usize);
+        add_primitive_cmp_ops!(BigEndian, isize, isize);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_primitive_cmp_ops!(BigEndian, f32, u32);
+        add_primitive_cmp_ops!(BigEndian, f64, u64);
+    }
+}
+
+#[cfg(feature = "little_endian")]
+mod le {
+    #[allow(unused_imports)]
+    use super::*;
+    #[cfg(feature = "byte_impls")]
+    mod bytes {
+        use super::*;
+        add_primitive_cmp_ops!(LittleEndian, bool, bool);
+        add_primitive_cmp_ops!(LittleEndian, u8, u8);
+        add_primitive_cmp_ops!(LittleEndian, i8, i8);
+    }
+
+    #[cfg(feature = "integer_impls")]
+    mod integers {
+        use super::*;
+        add_primitive_cmp_ops!(LittleEndian, u16, u16);
+        add_primitive_cmp_ops!(LittleEndian, i16, i16);
+        add_primitive_cmp_ops!(LittleEndian, u32, u32);
+        add_primitive_cmp_ops!(LittleEndian, i32, i32);
+        add_primitive_cmp_ops!(LittleEndian, u64, u64);
+        add_primitive_cmp_ops!(LittleEndian, i64, i64);
+        add_primitive_cmp_ops!(LittleEndian, u128, u128);
+        add_primitive_cmp_ops!(LittleEndian, i128, i128);
+        add_primitive_cmp_ops!(LittleEndian, usize, usize);
+        add_primitive_cmp_ops!(LittleEndian, isize, isize);
+    }
+
+    #[cfg(feature = "float_impls")]
+    mod floats {
+        use super::*;
+        add_primitive_cmp_ops!(LittleEndian, f32, u32);
+        add_primitive_cmp_ops!(LittleEndian, f64, u64);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -83,4 +234,57 @@ mod tests {
         let be2 = BigEndian::<f64, u64>::from(6234.5678);
         assert!(be1 < be2);
     }
+
+    #[test]
+    fn equality_le_test() {
+        let le1 = LittleEndian::from(0x12345678u32);
+        let le2 = LittleEndian::from(0x12345678u32);
+        let le3 = LittleEndian::from(0x78563412u32);
+        assert!(le1 == le2);
+        assert!(le1 != le3);
+    }
+
+    #[test]
+    fn nan_not_equal_fp() {
+        let be = f64be::from(f64::NAN);
+        let le = f32le::from(f32::NAN);
+        assert!(be != be);
+        assert!(le != le);
+    }
+
+    #[test]
+    fn signed_zero_equal_fp() {
+        assert!(f64be::from(0.0) == f64be::from(-0.0));
+        assert!(f32le::from(0.0) == f32le::from(-0.0));
+    }
+
+    #[test]
+    fn native_cmp_test() {
+        let be = u32be::from(5);
+        let le = i16le::from(-3);
+        assert!(be == 5);
+        assert!(5 == be);
+        assert!(be < 6 && 4 < be);
+        assert!(le == -3);
+        assert!(-4 < le && le <= -3);
+    }
+
+    #[test]
+    fn native_cmp_fp() {
+        let be = f64be::from(1.5);
+        assert!(be == 1.5);
+        assert!(be < 2.0);
+        let nan = f64be::from(f64::NAN);
+        assert!(nan != nan.to_native());
+    }
+
+    #[test]
+    fn cross_endian_cmp_test() {
+        let be = u32be::from(100);
+        let le = u32le::from(200);
+        assert!(be != le);
+        assert!(be < le);
+        assert!(le > be);
+        assert!(u32le::from(100) == be);
+    }
 }
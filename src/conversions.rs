@@ -0,0 +1,226 @@
+//! Conversions between wrappers of different types, mirroring the conversions between the primitive types in core.
+//!
+//! Lossless widenings are implemented with `From`, narrowing and sign changes with `TryFrom`, in and across either
+//! endianness.  Truncating conversions are available with `cast()` and `as_()`, which behave like `as`.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//! let len = u16be::from(1500);
+//! let wide: u32le = len.into();
+//! assert!(LittleEndian::<u8>::try_from(len).is_err());
+//! let low: BigEndian<u8> = len.cast();
+//! assert_eq!(wide, 1500);
+//! assert_eq!(low, 1500u16 as u8);
+//! ```
+//!
+//! `From` isn't implemented from a native value to a wider wrapper (e.g. `u16` to `u32be`), because it would make
+//! integer literals ambiguous in `u32be::from(5)`.  Use `u32be::from(u32::from(v))` instead.
+
+use super::*;
+
+/// Converts between the primitive types like an `as` cast.  This is what `cast()` and `as_()` on the wrappers use.
+pub trait PrimitiveCast<T> {
+    fn cast(self) -> T;
+}
+
+#[allow(clippy::unnecessary_cast)]
+mod primitive_cast {
+    use super::*;
+
+    macro_rules! add_primitive_cast {
+        ($from_ty:ty => $($to_ty:ty),+) => {
+            $(
+                impl PrimitiveCast<$to_ty> for $from_ty {
+                    fn cast(self) -> $to_ty {
+                        self as $to_ty
+                    }
+                }
+            )+
+        };
+    }
+
+    macro_rules! add_primitive_casts {
+        ($($from_ty:ty),+) => {
+            $(
+                add_primitive_cast!($from_ty => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+            )+
+        };
+    }
+
+    add_primitive_casts!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+    add_primitive_cast!(bool => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+}
+
+macro_rules! add_casts {
+    ($wrap_ty:ident) => {
+        impl<V, B> $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            /// Converts to a wrapper of another type in the same endianness, like an `as` cast on the native value.
+            pub fn cast<T, TB>(&self) -> $wrap_ty<T, TB>
+            where
+                V: PrimitiveCast<T>,
+                T: SpecificEndian<TB>,
+                TB: Copy,
+            {
+                $wrap_ty::from(self.to_native().cast())
+            }
+            /// Converts to a native value of another type, like an `as` cast on the native value.
+            pub fn as_<T>(&self) -> T
+            where
+                V: PrimitiveCast<T>,
+            {
+                self.to_native().cast()
+            }
+        }
+    };
+}
+
+add_casts!(BigEndian);
+add_casts!(LittleEndian);
+
+#[allow(unused_macros)]
+macro_rules! add_from_wrapper {
+    ($from_wrap:ident<$from_ty:ty, $from_bits:ty> => $to_wrap:ident<$to_ty:ty, $to_bits:ty>) => {
+        impl From<$from_wrap<$from_ty, $from_bits>> for $to_wrap<$to_ty, $to_bits> {
+            fn from(v: $from_wrap<$from_ty, $from_bits>) -> Self {
+                Self::from(<$to_ty>::from(v.to_native()))
+            }
+        }
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! add_try_from_wrapper {
+    ($from_wrap:ident<$from_ty:ty> => $to_wrap:ident<$to_ty:ty>) => {
+        impl TryFrom<$from_wrap<$from_ty, $from_ty>> for $to_wrap<$to_ty, $to_ty> {
+            type Error = <$to_ty as TryFrom<$from_ty>>::Error;
+
+            fn try_from(v: $from_wrap<$from_ty, $from_ty>) -> Result<Self, Self::Error> {
+                <$to_ty>::try_from(v.to_native()).map(Self::from)
+            }
+        }
+    };
+}
+
+/// `From` for every combination of endianness, for the lossless conversions in core.
+macro_rules! add_widening_conversions {
+    ($from_ty:ty, $from_bits:ty => $($to_ty:ty, $to_bits:ty);+) => {
+        $(
+            #[cfg(feature = "big_endian")]
+            add_from_wrapper!(BigEndian<$from_ty, $from_bits> => BigEndian<$to_ty, $to_bits>);
+            #[cfg(feature = "little_endian")]
+            add_from_wrapper!(LittleEndian<$from_ty, $from_bits> => LittleEndian<$to_ty, $to_bits>);
+            #[cfg(feature = "both_endian")]
+            add_from_wrapper!(BigEndian<$from_ty, $from_bits> => LittleEndian<$to_ty, $to_bits>);
+            #[cfg(feature = "both_endian")]
+            add_from_wrapper!(LittleEndian<$from_ty, $from_bits> => BigEndian<$to_ty, $to_bits>);
+        )+
+    };
+    ($from_ty:ty => $($to_ty:ty),+) => {
+        add_widening_conversions!($from_ty, $from_ty => $($to_ty, $to_ty);+);
+    };
+}
+
+/// `TryFrom` for every combination of endianness, for the fallible integer conversions in core.
+macro_rules! add_narrowing_conversions {
+    ($from_ty:ty => $($to_ty:ty),+) => {
+        $(
+            #[cfg(feature = "big_endian")]
+            add_try_from_wrapper!(BigEndian<$from_ty> => BigEndian<$to_ty>);
+            #[cfg(feature = "little_endian")]
+            add_try_from_wrapper!(LittleEndian<$from_ty> => LittleEndian<$to_ty>);
+            #[cfg(feature = "both_endian")]
+            add_try_from_wrapper!(BigEndian<$from_ty> => LittleEndian<$to_ty>);
+            #[cfg(feature = "both_endian")]
+            add_try_from_wrapper!(LittleEndian<$from_ty> => BigEndian<$to_ty>);
+        )+
+    };
+}
+
+add_widening_conversions!(bool => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+add_widening_conversions!(u8 => u16, u32, u64, u128, usize, i16, i32, i64, i128, isize);
+add_widening_conversions!(u16 => u32, u64, u128, usize, i32, i64, i128);
+add_widening_conversions!(u32 => u64, u128, i64, i128);
+add_widening_conversions!(u64 => u128, i128);
+add_widening_conversions!(i8 => i16, i32, i64, i128, isize);
+add_widening_conversions!(i16 => i32, i64, i128, isize);
+add_widening_conversions!(i32 => i64, i128);
+add_widening_conversions!(i64 => i128);
+
+#[cfg(feature = "float_impls")]
+mod float_conversions {
+    use super::*;
+    add_widening_conversions!(u8, u8 => f32, u32; f64, u64);
+    add_widening_conversions!(u16, u16 => f32, u32; f64, u64);
+    add_widening_conversions!(u32, u32 => f64, u64);
+    add_widening_conversions!(i8, i8 => f32, u32; f64, u64);
+    add_widening_conversions!(i16, i16 => f32, u32; f64, u64);
+    add_widening_conversions!(i32, i32 => f64, u64);
+    add_widening_conversions!(f32, u32 => f64, u64);
+}
+
+add_narrowing_conversions!(u8 => i8);
+add_narrowing_conversions!(u16 => u8, i8, i16, isize);
+add_narrowing_conversions!(u32 => u8, u16, usize, i8, i16, i32, isize);
+add_narrowing_conversions!(u64 => u8, u16, u32, usize, i8, i16, i32, i64, isize);
+add_narrowing_conversions!(u128 => u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+add_narrowing_conversions!(usize => u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, isize);
+add_narrowing_conversions!(i8 => u8, u16, u32, u64, u128, usize);
+add_narrowing_conversions!(i16 => u8, u16, u32, u64, u128, usize, i8);
+add_narrowing_conversions!(i32 => u8, u16, u32, u64, u128, usize, i8, i16, isize);
+add_narrowing_conversions!(i64 => u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+add_narrowing_conversions!(i128 => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, isize);
+add_narrowing_conversions!(isize => u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn widen() {
+        let be = u16be::from(0xfeed);
+        let a: u32be = be.into();
+        let b: u64le = be.into();
+        let c: i32le = be.into();
+        assert_eq!(a, 0xfeed);
+        assert_eq!(b, 0xfeed);
+        assert_eq!(c, 0xfeed);
+        assert_eq!(i64be::from(LittleEndian::<i8>::from(-5)), -5);
+        assert_eq!(f64le::from(f32be::from(1.5)), 1.5);
+        assert_eq!(f32be::from(BigEndian::<u8>::from(200)), 200.0);
+    }
+
+    #[test]
+    fn narrow() {
+        assert_eq!(
+            u16le::try_from(u32be::from(0xbeef)),
+            Ok(u16le::from(0xbeef))
+        );
+        assert!(u16be::try_from(u32be::from(0x10000)).is_err());
+        assert_eq!(
+            BigEndian::<i8>::try_from(i64le::from(-128)),
+            Ok(BigEndian::from(-128))
+        );
+        assert!(u32le::try_from(i32be::from(-1)).is_err());
+        assert_eq!(
+            u32be::try_from(i16le::from(-1)).unwrap_err(),
+            u32::try_from(-1i16).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn cast() {
+        let be = u32be::from(0x1234_5678);
+        let low: u16be = be.cast();
+        let signed: i32le = LittleEndian::<u32>::from(u32::MAX).cast();
+        let int: i16be = f64be::from(-3.75).cast();
+        assert_eq!(low, 0x5678);
+        assert_eq!(signed, -1);
+        assert_eq!(int, -3);
+        assert_eq!(be.as_::<u8>(), 0x78);
+        assert_eq!(be.as_::<f64>(), 305419896.0);
+    }
+}
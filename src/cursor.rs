@@ -0,0 +1,329 @@
+//! A reader and a writer over byte slices, for parsing and building packets without `std::io`.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//!
+//! let packet = [0x45, 0x00, 0x00, 0x1c, 0xde, 0xad, 0x00, 0x00, 0x2a, 0x00];
+//! let mut reader = EndianReader::new(&packet);
+//! let version = reader.read_be::<u8>()?;
+//! reader.skip(1)?;
+//! let len: u16be = reader.read()?;
+//! let id = reader.peek::<u16be>()?;
+//! reader.skip(2)?;
+//! reader.align_to(4)?;
+//! assert_eq!((version, len.to_native(), id.to_native()), (0x45, 0x1c, 0xdead));
+//! assert_eq!(reader.read_le::<u16>()?, 0x2a);
+//! assert_eq!(
+//!     reader.read::<u32be>(),
+//!     Err(ShortBuffer { position: 10, needed: 4, available: 0 })
+//! );
+//!
+//! let mut out = [0xff; 8];
+//! let mut writer = EndianWriter::new(&mut out);
+//! writer.write(u16be::from(0xcafe))?;
+//! writer.write_le(7u8)?;
+//! writer.align_to(4)?;
+//! writer.write(f32le::from(1.0))?;
+//! assert_eq!(writer.written(), [0xca, 0xfe, 7, 0, 0, 0, 0x80, 0x3f]);
+//! # Ok::<(), ShortBuffer>(())
+//! ```
+//!
+//! Native values are read and written with `read_be()`/`read_le()` and `write_be()`/`write_le()` for the types that
+//! are stored as themselves, i.e. integers.  Floats and other types go through their wrappers, e.g.
+//! `reader.read::<f64be>()?.to_native()`.
+
+use core::fmt::{self, Display, Formatter};
+
+use super::*;
+
+/// Returned when the buffer ends before a read or write.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ShortBuffer {
+    /// The position of the reader or writer, which is left unchanged.
+    pub position: usize,
+    /// The number of bytes the operation needed.
+    pub needed: usize,
+    /// The number of bytes left in the buffer.
+    pub available: usize,
+}
+
+impl Display for ShortBuffer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer too short: needed {} bytes at position {}, but only {} are left",
+            self.needed, self.position, self.available
+        )
+    }
+}
+
+impl core::error::Error for ShortBuffer {}
+
+/// The number of bytes needed to get from `pos` to a multiple of `align`.
+fn padding_to(pos: usize, align: usize) -> usize {
+    assert!(align > 0, "alignment must not be zero");
+    (align - pos % align) % align
+}
+
+/// Reads endian values from a byte slice, see the module docs.
+#[derive(Debug, Clone)]
+pub struct EndianReader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> EndianReader<'a> {
+    /// Creates a reader at the start of `buf`.
+    pub const fn new(buf: &'a [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// The number of bytes read or skipped so far.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes left.
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// Whether all bytes have been read.
+    pub const fn is_empty(&self) -> bool {
+        self.remaining() == 0
+    }
+
+    /// The bytes that haven't been read yet.
+    pub fn remaining_slice(&self) -> &'a [u8] {
+        &self.buf[self.pos..]
+    }
+
+    fn short(&self, needed: usize) -> ShortBuffer {
+        ShortBuffer {
+            position: self.pos,
+            needed,
+            available: self.remaining(),
+        }
+    }
+
+    /// Returns the next `n` bytes without advancing.
+    pub fn peek_bytes(&self, n: usize) -> Result<&'a [u8], ShortBuffer> {
+        self.remaining_slice().get(..n).ok_or(self.short(n))
+    }
+
+    /// Returns the next `n` bytes and advances past them.
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8], ShortBuffer> {
+        let bytes = self.peek_bytes(n)?;
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    /// Returns a reader over the next `n` bytes, e.g. for the payload of a packet, and advances past them.
+    pub fn sub_reader(&mut self, n: usize) -> Result<EndianReader<'a>, ShortBuffer> {
+        self.read_bytes(n).map(EndianReader::new)
+    }
+
+    /// Advances by `n` bytes.
+    pub fn skip(&mut self, n: usize) -> Result<(), ShortBuffer> {
+        self.read_bytes(n).map(|_| ())
+    }
+
+    /// Advances to the next multiple of `align`, counted from the start of the buffer.  Panics if `align` is zero.
+    pub fn align_to(&mut self, align: usize) -> Result<(), ShortBuffer> {
+        self.skip(padding_to(self.pos, align))
+    }
+
+    /// Reads a wrapper, e.g. `reader.read::<u32be>()`.
+    pub fn read<T: EndianBytes>(&mut self) -> Result<T, ShortBuffer> {
+        let v = self.peek()?;
+        self.pos += T::SIZE;
+        Ok(v)
+    }
+
+    /// Reads a wrapper without advancing.
+    pub fn peek<T: EndianBytes>(&self) -> Result<T, ShortBuffer> {
+        let bytes = self.peek_bytes(T::SIZE)?;
+        Ok(T::from_bytes(bytes).unwrap())
+    }
+
+    /// Fills `values` with the next wrappers.
+    pub fn read_into<T: EndianBytes>(&mut self, values: &mut [T]) -> Result<(), ShortBuffer> {
+        let bytes = self.read_bytes(core::mem::size_of_val(values))?;
+        T::slice_as_bytes_mut(values).copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Reads a big-endian value and converts it to native endian.
+    pub fn read_be<V: SpecificEndian<V>>(&mut self) -> Result<V, ShortBuffer>
+    where
+        BigEndian<V>: EndianBytes,
+    {
+        self.read::<BigEndian<V>>().map(|v| v.to_native())
+    }
+
+    /// Reads a little-endian value and converts it to native endian.
+    pub fn read_le<V: SpecificEndian<V>>(&mut self) -> Result<V, ShortBuffer>
+    where
+        LittleEndian<V>: EndianBytes,
+    {
+        self.read::<LittleEndian<V>>().map(|v| v.to_native())
+    }
+}
+
+/// Writes endian values into a byte slice, see the module docs.
+#[derive(Debug)]
+pub struct EndianWriter<'a> {
+    buf: &'a mut [u8],
+    pos: usize,
+}
+
+impl<'a> EndianWriter<'a> {
+    /// Creates a writer at the start of `buf`.
+    pub fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, pos: 0 }
+    }
+
+    /// The number of bytes written so far.
+    pub const fn position(&self) -> usize {
+        self.pos
+    }
+
+    /// The number of bytes that can still be written.
+    pub const fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    /// The bytes written so far.
+    pub fn written(&self) -> &[u8] {
+        &self.buf[..self.pos]
+    }
+
+    /// Consumes the writer and returns the bytes written.
+    pub fn into_written(self) -> &'a mut [u8] {
+        &mut self.buf[..self.pos]
+    }
+
+    /// Reserves the next `n` bytes and advances past them.
+    fn advance(&mut self, n: usize) -> Result<&mut [u8], ShortBuffer> {
+        if n > self.remaining() {
+            return Err(ShortBuffer {
+                position: self.pos,
+                needed: n,
+                available: self.remaining(),
+            });
+        }
+        let start = self.pos;
+        self.pos += n;
+        Ok(&mut self.buf[start..self.pos])
+    }
+
+    /// Writes raw bytes.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), ShortBuffer> {
+        self.advance(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes `n` copies of `byte`.
+    pub fn pad(&mut self, n: usize, byte: u8) -> Result<(), ShortBuffer> {
+        self.advance(n)?.fill(byte);
+        Ok(())
+    }
+
+    /// Writes zeros up to the next multiple of `align`, counted from the start of the buffer.  Panics if `align` is
+    /// zero.
+    pub fn align_to(&mut self, align: usize) -> Result<(), ShortBuffer> {
+        self.pad(padding_to(self.pos, align), 0)
+    }
+
+    /// Writes a wrapper, e.g. `writer.write(u32be::from(7))`.
+    pub fn write<T: EndianBytes>(&mut self, v: T) -> Result<(), ShortBuffer> {
+        self.write_bytes(v.as_bytes())
+    }
+
+    /// Writes all `values`.
+    pub fn write_slice<T: EndianBytes>(&mut self, values: &[T]) -> Result<(), ShortBuffer> {
+        self.write_bytes(T::slice_as_bytes(values))
+    }
+
+    /// Converts a native value to big endian and writes it.
+    pub fn write_be<V: SpecificEndian<V>>(&mut self, v: V) -> Result<(), ShortBuffer>
+    where
+        BigEndian<V>: EndianBytes,
+    {
+        self.write(BigEndian::from(v))
+    }
+
+    /// Converts a native value to little endian and writes it.
+    pub fn write_le<V: SpecificEndian<V>>(&mut self, v: V) -> Result<(), ShortBuffer>
+    where
+        LittleEndian<V>: EndianBytes,
+    {
+        self.write(LittleEndian::from(v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn reader() {
+        let buf = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+        let mut reader = EndianReader::new(&buf);
+        assert_eq!(reader.peek::<u16le>(), Ok(u16le::from(0x0201)));
+        assert_eq!(reader.read_be::<u16>(), Ok(0x0102));
+        let mut sub = reader.sub_reader(4).unwrap();
+        assert_eq!(sub.read::<i32be>(), Ok(i32be::from(0x03040506)));
+        assert!(sub.is_empty());
+        assert_eq!(reader.position(), 6);
+        reader.align_to(4).unwrap();
+        assert_eq!(reader.remaining_slice(), [9]);
+        let mut pair = [u16be::from(0); 2];
+        assert_eq!(
+            reader.read_into(&mut pair),
+            Err(ShortBuffer {
+                position: 8,
+                needed: 4,
+                available: 1
+            })
+        );
+        assert_eq!(reader.position(), 8);
+        assert_eq!(reader.read_bytes(1), Ok(&[9u8][..]));
+        reader.align_to(1).unwrap();
+    }
+
+    #[test]
+    fn writer() {
+        let mut buf = [0xaa; 12];
+        let mut writer = EndianWriter::new(&mut buf);
+        writer.write_be(0x0102u16).unwrap();
+        writer.pad(1, 0xee).unwrap();
+        writer.align_to(4).unwrap();
+        writer
+            .write_slice(&[i16le::from(-2), i16le::from(3)])
+            .unwrap();
+        assert_eq!(writer.remaining(), 4);
+        assert_eq!(
+            writer.write(f64be::from(1.0)),
+            Err(ShortBuffer {
+                position: 8,
+                needed: 8,
+                available: 4
+            })
+        );
+        writer.write(f32be::from(-0.0)).unwrap();
+        assert_eq!(
+            writer.into_written(),
+            [1, 2, 0xee, 0, 0xfe, 0xff, 3, 0, 0x80, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn error_message() {
+        let err = EndianReader::new(&[0]).read::<u32le>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "buffer too short: needed 4 bytes at position 0, but only 1 are left"
+        );
+    }
+}
@@ -0,0 +1,116 @@
+//! Extension traits for `embedded-io`, to read and write endian values on targets without `std::io`.
+//!
+//! ```rust
+//! use simple_endian_wrapper::embedded::{ReadEndianExt, WriteEndianExt};
+//! use simple_endian_wrapper::*;
+//!
+//! let mut buf = [0u8; 6];
+//! let mut out = &mut buf[..];
+//! out.write_endian(u16be::from(0x1234)).unwrap();
+//! out.write_endian(f32le::from(1.0)).unwrap();
+//!
+//! let mut uart = &buf[..];
+//! assert_eq!(uart.read_endian::<u16be>()?, 0x1234);
+//! assert_eq!(uart.read_endian::<f32le>()?, 1.0);
+//! assert_eq!(uart.read_endian::<u16le>(), Err(embedded_io::ReadExactError::UnexpectedEof));
+//! # Ok::<(), embedded_io::ReadExactError<core::convert::Infallible>>(())
+//! ```
+//!
+//! Reads have the semantics of `read_exact()`: they fail with `ReadExactError::UnexpectedEof` if the source ends
+//! early, and pass the errors of the source through as `ReadExactError::Other`.
+//!
+//! The extension traits of this module and of `embedded_async`, `tokio_ext` and `futures_ext` aren't exported from
+//! the crate root.  They share their names, and many types, like `&[u8]`, implement the I/O traits of several of these
+//! crates, which would make the method calls ambiguous.  Import the ones for the I/O traits in use instead.
+
+use ::embedded_io::{Read, ReadExactError, Write};
+
+use super::*;
+
+/// Reads endian values from any `embedded_io::Read`.
+pub trait ReadEndianExt: Read {
+    /// Reads one value, e.g. `uart.read_endian::<u32be>()`.
+    fn read_endian<T: EndianBytes>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+        let mut v = T::zeroed();
+        self.read_exact(v.as_bytes_mut())?;
+        Ok(v)
+    }
+
+    /// Fills `values` directly from the source.
+    fn read_endian_into<T: EndianBytes>(
+        &mut self,
+        values: &mut [T],
+    ) -> Result<(), ReadExactError<Self::Error>> {
+        self.read_exact(T::slice_as_bytes_mut(values))
+    }
+}
+
+impl<R: Read + ?Sized> ReadEndianExt for R {}
+
+/// Writes endian values to any `embedded_io::Write`.
+pub trait WriteEndianExt: Write {
+    /// Writes one value, e.g. `uart.write_endian(u16le::from(7))`.
+    fn write_endian<T: EndianBytes>(&mut self, v: T) -> Result<(), Self::Error> {
+        self.write_all(v.as_bytes())
+    }
+
+    /// Writes all `values` with a single `write_all`.
+    fn write_endian_slice<T: EndianBytes>(&mut self, values: &[T]) -> Result<(), Self::Error> {
+        self.write_all(T::slice_as_bytes(values))
+    }
+}
+
+impl<W: Write + ?Sized> WriteEndianExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReadEndianExt, WriteEndianExt};
+    use crate::*;
+    use embedded_io::{ErrorKind, ErrorType, Read, ReadExactError};
+
+    /// A source that fails after a number of bytes, like a UART with a framing error.
+    struct Faulty(usize);
+
+    impl ErrorType for Faulty {
+        type Error = ErrorKind;
+    }
+
+    impl Read for Faulty {
+        fn read(&mut self, buf: &mut [u8]) -> Result<usize, ErrorKind> {
+            if self.0 == 0 {
+                return Err(ErrorKind::InvalidData);
+            }
+            let n = buf.len().min(self.0);
+            buf[..n].fill(0xff);
+            self.0 -= n;
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0u8; 12];
+        let mut out = &mut buf[..];
+        out.write_endian_slice(&[i32be::from(-2), i32be::from(3)])
+            .unwrap();
+        out.write_endian(u32le::from(0xdeadbeef)).unwrap();
+        assert!(out.is_empty());
+
+        let mut src = &buf[..];
+        let mut values = [i32be::from(0); 2];
+        src.read_endian_into(&mut values).unwrap();
+        assert_eq!(values, [i32be::from(-2), i32be::from(3)]);
+        assert_eq!(src.read_endian::<u32le>(), Ok(u32le::from(0xdeadbeef)));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Faulty(8).read_endian::<i64be>(), Ok(i64be::from(-1)));
+        assert_eq!(
+            Faulty(2).read_endian::<u32be>(),
+            Err(ReadExactError::Other(ErrorKind::InvalidData))
+        );
+        let mut full = [0u8; 1];
+        assert!((&mut full[..]).write_endian(u16le::from(1)).is_err());
+    }
+}
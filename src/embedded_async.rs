@@ -0,0 +1,93 @@
+//! Extension traits for `embedded-io-async`, the async counterpart of the `embedded` module.
+//!
+//! ```rust
+//! use simple_endian_wrapper::embedded_async::ReadEndianExt;
+//! use simple_endian_wrapper::*;
+//!
+//! async fn read_header<R: embedded_io_async::Read>(
+//!     uart: &mut R,
+//! ) -> Result<(u16, u32), embedded_io_async::ReadExactError<R::Error>> {
+//!     let kind = uart.read_endian::<u16be>().await?;
+//!     let len = uart.read_endian::<u32le>().await?;
+//!     Ok((kind.to_native(), len.to_native()))
+//! }
+//! ```
+//!
+//! The traits aren't exported from the crate root, see the `embedded` module.
+
+use ::embedded_io_async::{Read, ReadExactError, Write};
+
+use super::*;
+
+/// Reads endian values from any `embedded_io_async::Read`.
+#[allow(async_fn_in_trait)]
+pub trait ReadEndianExt: Read {
+    /// Reads one value, e.g. `uart.read_endian::<u32be>().await`.
+    async fn read_endian<T: EndianBytes>(&mut self) -> Result<T, ReadExactError<Self::Error>> {
+        let mut v = T::zeroed();
+        self.read_exact(v.as_bytes_mut()).await?;
+        Ok(v)
+    }
+
+    /// Fills `values` directly from the source.
+    async fn read_endian_into<T: EndianBytes>(
+        &mut self,
+        values: &mut [T],
+    ) -> Result<(), ReadExactError<Self::Error>> {
+        self.read_exact(T::slice_as_bytes_mut(values)).await
+    }
+}
+
+impl<R: Read + ?Sized> ReadEndianExt for R {}
+
+/// Writes endian values to any `embedded_io_async::Write`.
+#[allow(async_fn_in_trait)]
+pub trait WriteEndianExt: Write {
+    /// Writes one value, e.g. `uart.write_endian(u16le::from(7)).await`.
+    async fn write_endian<T: EndianBytes>(&mut self, v: T) -> Result<(), Self::Error> {
+        self.write_all(v.as_bytes()).await
+    }
+
+    /// Writes all `values` with a single `write_all`.
+    async fn write_endian_slice<T: EndianBytes>(
+        &mut self,
+        values: &[T],
+    ) -> Result<(), Self::Error> {
+        self.write_all(T::slice_as_bytes(values)).await
+    }
+}
+
+impl<W: Write + ?Sized> WriteEndianExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use super::{ReadEndianExt, WriteEndianExt};
+    use crate::test_util::block_on;
+    use crate::*;
+    use embedded_io_async::ReadExactError;
+
+    #[test]
+    fn round_trip() {
+        let mut buf = [0u8; 10];
+        block_on(async {
+            let mut out = &mut buf[..];
+            out.write_endian(u16le::from(0x0102)).await.unwrap();
+            out.write_endian_slice(&[u32be::from(3), u32be::from(4)])
+                .await
+                .unwrap();
+        });
+        assert_eq!(buf, [2, 1, 0, 0, 0, 3, 0, 0, 0, 4]);
+
+        block_on(async {
+            let mut src = &buf[..];
+            assert_eq!(src.read_endian::<u16le>().await, Ok(u16le::from(0x0102)));
+            let mut values = [u32be::from(0); 2];
+            src.read_endian_into(&mut values).await.unwrap();
+            assert_eq!(values, [u32be::from(3), u32be::from(4)]);
+            assert_eq!(
+                src.read_endian::<u16le>().await,
+                Err(ReadExactError::UnexpectedEof)
+            );
+        });
+    }
+}
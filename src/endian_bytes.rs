@@ -0,0 +1,209 @@
+//! Access to the bytes of the wrappers, which are already in the declared byte order.  This is what the readers and
+//! writers use to move values without any conversion.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//! let magic = u32be::from(0xCAFEBABE);
+//! assert_eq!(magic.as_bytes(), [0xCA, 0xFE, 0xBA, 0xBE]);
+//!
+//! let mut words = [u16le::from(0); 2];
+//! u16le::slice_as_bytes_mut(&mut words).copy_from_slice(&[1, 0, 2, 0]);
+//! assert_eq!(words, [u16le::from(1), u16le::from(2)]);
+//! assert_eq!(f32le::from_bytes(&1.5f32.to_le_bytes()), Some(f32le::from(1.5)));
+//! ```
+//!
+//! Wire structs made of wrappers get the same access by implementing [`EndianStruct`].
+
+use super::*;
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Wrappers that are stored as a plain integer, so that every byte pattern is a valid value.  This includes floats
+/// and other types that are stored as integers, but not `bool` or the `NonZero` types, as well as the structs and
+/// arrays that implement `EndianStruct`.
+pub trait EndianBytes: Copy + private::Sealed {
+    /// The size of the value in bytes.
+    const SIZE: usize;
+
+    /// A value with all bytes zero.
+    fn zeroed() -> Self;
+
+    /// Copies a value from `bytes`, or returns `None` if the length isn't `SIZE`.
+    fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != Self::SIZE {
+            return None;
+        }
+        let mut v = Self::zeroed();
+        v.as_bytes_mut().copy_from_slice(bytes);
+        Some(v)
+    }
+
+    /// The bytes of the value in the declared byte order.
+    fn as_bytes(&self) -> &[u8] {
+        Self::slice_as_bytes(core::slice::from_ref(self))
+    }
+
+    /// The bytes of the value in the declared byte order, for filling it in place.
+    fn as_bytes_mut(&mut self) -> &mut [u8] {
+        Self::slice_as_bytes_mut(core::slice::from_mut(self))
+    }
+
+    /// The bytes of all values, in the declared byte order.
+    fn slice_as_bytes(values: &[Self]) -> &[u8] {
+        // Safety: the sealed impls are repr(transparent) over integers, or EndianStructs, which have no padding.
+        unsafe {
+            core::slice::from_raw_parts(
+                values.as_ptr() as *const u8,
+                core::mem::size_of_val(values),
+            )
+        }
+    }
+
+    /// The bytes of all values, for filling them in place, e.g. from a reader.
+    fn slice_as_bytes_mut(values: &mut [Self]) -> &mut [u8] {
+        // Safety: the sealed impls are made of integers, for which every byte pattern is valid.
+        unsafe {
+            core::slice::from_raw_parts_mut(
+                values.as_mut_ptr() as *mut u8,
+                core::mem::size_of_val(values),
+            )
+        }
+    }
+}
+
+/// Marks a struct of wrappers as plain bytes, so that it can be read and written as a whole, e.g. with
+/// `reader.read_endian::<Header>()`.
+///
+/// ```rust
+/// use simple_endian_wrapper::*;
+///
+/// #[derive(Clone, Copy)]
+/// #[repr(C)]
+/// struct Header {
+///     kind: u16be,
+///     flags: u16le,
+///     len: u32be,
+/// }
+///
+/// // Safety: repr(C), the fields are wrappers, and `len` starts at offset 4, so there is no padding.
+/// unsafe impl EndianStruct for Header {}
+///
+/// let header = Header::from_bytes(&[0, 1, 2, 0, 0, 0, 0, 8]).unwrap();
+/// assert_eq!(header.kind, 1);
+/// assert_eq!(header.len, 8);
+/// assert_eq!(header.as_bytes(), [0, 1, 2, 0, 0, 0, 0, 8]);
+/// ```
+///
+/// The readers, writers, codecs and parsers of this crate accept every `EndianStruct` wherever they accept a single
+/// wrapper.  Arrays of wrappers implement it already.
+///
+/// # Safety
+///
+/// The struct must be `#[repr(C)]` or `#[repr(transparent)]`, have only fields that implement `EndianBytes`, and
+/// have no padding between or after them.  Every bit pattern of the fields is then a valid value, and all bytes of the
+/// struct are initialized, which `as_bytes()` and `from_bytes()` rely on.  Fields with a larger alignment than the ones
+/// before them add padding, e.g. a `u32be` after a single `u16be`.
+pub unsafe trait EndianStruct: Copy {}
+
+// Safety: arrays of EndianBytes have no padding.
+unsafe impl<T: EndianBytes, const N: usize> EndianStruct for [T; N] {}
+
+impl<T: EndianStruct> private::Sealed for T {}
+
+impl<T: EndianStruct> EndianBytes for T {
+    const SIZE: usize = core::mem::size_of::<T>();
+
+    fn zeroed() -> Self {
+        // Safety: all fields are made of integers, for which zero is valid.
+        unsafe { core::mem::zeroed() }
+    }
+}
+
+macro_rules! add_endian_bytes {
+    ($wrap_ty:ident, $($bit_ty:ty),+) => {
+        $(
+            impl<V: SpecificEndian<$bit_ty>> private::Sealed for $wrap_ty<V, $bit_ty> {}
+
+            impl<V: SpecificEndian<$bit_ty>> EndianBytes for $wrap_ty<V, $bit_ty> {
+                const SIZE: usize = core::mem::size_of::<$bit_ty>();
+
+                fn zeroed() -> Self {
+                    Self::from_bits(0)
+                }
+            }
+        )+
+    };
+}
+
+add_endian_bytes!(BigEndian, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+add_endian_bytes!(
+    LittleEndian,
+    u8,
+    u16,
+    u32,
+    u64,
+    u128,
+    usize,
+    i8,
+    i16,
+    i32,
+    i64,
+    i128,
+    isize
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn bytes_in_declared_order() {
+        assert_eq!(u32be::from(0x01020304).as_bytes(), [1, 2, 3, 4]);
+        assert_eq!(u32le::from(0x01020304).as_bytes(), [4, 3, 2, 1]);
+        assert_eq!(f64be::from(1.0).as_bytes(), 1.0f64.to_be_bytes());
+        assert_eq!(i16be::SIZE, 2);
+        assert_eq!(
+            i16be::slice_as_bytes(&[i16be::from(-2), i16be::from(1)]),
+            [0xff, 0xfe, 0x00, 0x01]
+        );
+    }
+
+    #[test]
+    fn from_bytes() {
+        assert_eq!(u16be::from_bytes(&[0x12, 0x34]), Some(u16be::from(0x1234)));
+        assert_eq!(u16be::from_bytes(&[0x12]), None);
+        let mut v = u64le::zeroed();
+        v.as_bytes_mut()[0] = 7;
+        assert_eq!(v, 7);
+    }
+
+    #[test]
+    fn structs() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[repr(C)]
+        struct Pair {
+            a: u16be,
+            b: [i16be; 3],
+            c: f32le,
+        }
+        unsafe impl EndianStruct for Pair {}
+
+        let pair = Pair {
+            a: 0x0102.into(),
+            b: [3.into(), (-1).into(), 0.into()],
+            c: 1.0.into(),
+        };
+        assert_eq!(Pair::SIZE, 12);
+        assert_eq!(
+            pair.as_bytes(),
+            [1, 2, 0, 3, 0xff, 0xff, 0, 0, 0, 0, 0x80, 0x3f]
+        );
+        assert_eq!(Pair::from_bytes(pair.as_bytes()), Some(pair));
+        assert_eq!(
+            <[u32le; 2]>::from_bytes(&[1, 0, 0, 0, 2, 0, 0, 0]),
+            Some([u32le::from(1), u32le::from(2)])
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Support for types generated by `bitflags!`, so that they can be stored in the wrappers, e.g.
+//! `BigEndian<Flags, u32>`.
+//!
+//! ```rust
+//! use bitflags::bitflags;
+//! use simple_endian_wrapper::*;
+//!
+//! bitflags! {
+//!     #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+//!     pub struct Permissions: u32 {
+//!         const READ = 0b001;
+//!         const WRITE = 0b010;
+//!         const EXECUTE = 0b100;
+//!     }
+//! }
+//! impl EndianFlags for Permissions {}
+//!
+//! let mut perms = BigEndian::from(Permissions::READ);
+//! perms.insert(Permissions::WRITE);
+//! assert!(perms.contains(Permissions::READ | Permissions::WRITE));
+//! perms &= !BigEndian::from(Permissions::READ);
+//! assert_eq!(perms.to_native(), Permissions::WRITE);
+//! assert_eq!(perms.to_bits(), 0b010u32.to_be());
+//! ```
+//!
+//! The flag operations and `|`, `&`, `^` and `!` work on the stored bits, so they need no conversion besides that of
+//! constant operands.  `-` comes from the `math_ops` feature and converts like other arithmetic.
+//!
+//! Bits that aren't defined by the flags type are preserved by `from()` and `to_native()`, so that a header is written
+//! back exactly as it was read.  To reject them use `try_to_native()`, to drop them use `to_native_truncate()`.
+
+use ::bitflags::{Bits, Flags};
+use core::hash::{Hash, Hasher};
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
+
+use super::*;
+
+/// Marks a type generated by `bitflags!` to be stored in the wrappers.  This implements `SpecificEndian` for it, so
+/// that it can be used like `BigEndian<Flags, u32>`.
+pub trait EndianFlags: Flags + Copy {}
+
+impl<F> SpecificEndian<F::Bits> for F
+where
+    F: EndianFlags,
+    F::Bits: SpecificEndian<F::Bits>,
+{
+    fn to_big_endian(&self) -> F::Bits {
+        self.bits().to_big_endian()
+    }
+    fn to_little_endian(&self) -> F::Bits {
+        self.bits().to_little_endian()
+    }
+    fn from_big_endian(value: F::Bits) -> Self {
+        F::from_bits_retain(F::Bits::from_big_endian(value))
+    }
+    fn from_little_endian(value: F::Bits) -> Self {
+        F::from_bits_retain(F::Bits::from_little_endian(value))
+    }
+}
+
+// Unknown bits are retained, so the conversion is a bijection and the raw bits can be compared and hashed directly.
+impl<F> EndianRepr<F::Bits> for F
+where
+    F: EndianFlags,
+    F::Bits: EndianRepr<F::Bits>,
+{
+    fn eq_big_endian(a: &F::Bits, b: &F::Bits) -> bool {
+        a == b
+    }
+    fn eq_little_endian(a: &F::Bits, b: &F::Bits) -> bool {
+        a == b
+    }
+    fn hash_big_endian<H: Hasher>(value: &F::Bits, state: &mut H)
+    where
+        F::Bits: Hash,
+    {
+        value.hash(state)
+    }
+    fn hash_little_endian<H: Hasher>(value: &F::Bits, state: &mut H)
+    where
+        F::Bits: Hash,
+    {
+        value.hash(state)
+    }
+    fn hash_slice_big_endian<H: Hasher>(values: &[F::Bits], state: &mut H)
+    where
+        F::Bits: Hash,
+    {
+        F::Bits::hash_slice(values, state)
+    }
+    fn hash_slice_little_endian<H: Hasher>(values: &[F::Bits], state: &mut H)
+    where
+        F::Bits: Hash,
+    {
+        F::Bits::hash_slice(values, state)
+    }
+    fn fmt_stored_bytes(value: &F::Bits, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        F::Bits::fmt_stored_bytes(value, f)
+    }
+}
+
+/// The bitwise operators on flags, with another wrapper or a constant of the flags type as right operand.
+macro_rules! add_flags_op {
+    ($wrap_ty:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident, $method:ident) => {
+        impl<F> $op for $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self {
+                self.$method(rhs)
+            }
+        }
+        impl<F> $op<F> for $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: F) -> Self {
+                self.$method(Self::from(rhs))
+            }
+        }
+        impl<F> $op_assign for $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                *self = self.$method(rhs);
+            }
+        }
+        impl<F> $op_assign<F> for $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            fn $op_assign_fn(&mut self, rhs: F) {
+                *self = self.$method(Self::from(rhs));
+            }
+        }
+    };
+}
+
+macro_rules! add_flags_methods {
+    ($wrap_ty:ident) => {
+        impl<F> $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            /// Whether no flags are set, including unknown bits.
+            pub fn is_empty(&self) -> bool {
+                self.0 == F::Bits::EMPTY
+            }
+            /// Whether all flags in `other` are set.
+            pub fn contains(&self, other: F) -> bool {
+                let other = Self::from(other).0;
+                self.0 & other == other
+            }
+            /// Whether any flag in `other` is set.
+            pub fn intersects(&self, other: F) -> bool {
+                self.0 & Self::from(other).0 != F::Bits::EMPTY
+            }
+            /// Sets the flags in `other`.
+            pub fn insert(&mut self, other: F) {
+                *self = self.union(Self::from(other));
+            }
+            /// Clears the flags in `other`.
+            pub fn remove(&mut self, other: F) {
+                *self = self.difference(Self::from(other));
+            }
+            /// Flips the flags in `other`.
+            pub fn toggle(&mut self, other: F) {
+                *self = self.symmetric_difference(Self::from(other));
+            }
+            /// Sets or clears the flags in `other`.
+            pub fn set(&mut self, other: F, value: bool) {
+                if value {
+                    self.insert(other)
+                } else {
+                    self.remove(other)
+                }
+            }
+
+            /// The bitwise or of both values.
+            pub fn union(self, other: Self) -> Self {
+                Self::from_bits(self.0 | other.0)
+            }
+            /// The bitwise and of both values.
+            pub fn intersection(self, other: Self) -> Self {
+                Self::from_bits(self.0 & other.0)
+            }
+            /// The bitwise xor of both values.
+            pub fn symmetric_difference(self, other: Self) -> Self {
+                Self::from_bits(self.0 ^ other.0)
+            }
+            /// The flags of `self` that aren't set in `other`.
+            pub fn difference(self, other: Self) -> Self {
+                Self::from_bits(self.0 & !other.0)
+            }
+            /// The known flags that aren't set in `self`.  Like `complement` in bitflags this drops unknown bits.
+            pub fn complement(self) -> Self {
+                Self::from_bits(!self.0 & Self::from(F::all()).0)
+            }
+
+            /// Converts to the flags type, or `None` if any bits are set that the flags type doesn't define.
+            pub fn try_to_native(&self) -> Option<F> {
+                F::from_bits(self.to_native().bits())
+            }
+            /// Converts to the flags type, dropping any bits that the flags type doesn't define.
+            pub fn to_native_truncate(&self) -> F {
+                F::from_bits_truncate(self.to_native().bits())
+            }
+        }
+    };
+}
+
+add_flags_methods!(BigEndian);
+add_flags_methods!(LittleEndian);
+
+macro_rules! add_flags_ops {
+    ($wrap_ty:ident) => {
+        add_flags_op!($wrap_ty, BitOr, bitor, BitOrAssign, bitor_assign, union);
+        add_flags_op!(
+            $wrap_ty,
+            BitAnd,
+            bitand,
+            BitAndAssign,
+            bitand_assign,
+            intersection
+        );
+        add_flags_op!(
+            $wrap_ty,
+            BitXor,
+            bitxor,
+            BitXorAssign,
+            bitxor_assign,
+            symmetric_difference
+        );
+
+        impl<F> Not for $wrap_ty<F, F::Bits>
+        where
+            F: EndianFlags,
+            F::Bits: SpecificEndian<F::Bits>,
+        {
+            type Output = Self;
+
+            fn not(self) -> Self {
+                self.complement()
+            }
+        }
+    };
+}
+
+add_flags_ops!(BigEndian);
+add_flags_ops!(LittleEndian);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use bitflags::bitflags;
+
+    bitflags! {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        struct TcpFlags: u16 {
+            const FIN = 1 << 0;
+            const SYN = 1 << 1;
+            const RST = 1 << 2;
+            const ACK = 1 << 4;
+        }
+    }
+    impl EndianFlags for TcpFlags {}
+
+    #[test]
+    fn stored_bits() {
+        let be = BigEndian::from(TcpFlags::SYN | TcpFlags::ACK);
+        let le = LittleEndian::from(TcpFlags::SYN | TcpFlags::ACK);
+        assert_eq!(be.to_bits(), 0x12u16.to_be());
+        assert_eq!(le.to_bits(), 0x12u16.to_le());
+        assert_eq!(be.to_native(), le.to_native());
+    }
+
+    #[test]
+    fn flag_methods() {
+        let mut be: BigEndian<TcpFlags, u16> = BigEndian::from(TcpFlags::empty());
+        assert!(be.is_empty());
+        be.insert(TcpFlags::SYN);
+        be.set(TcpFlags::ACK, true);
+        assert!(be.contains(TcpFlags::SYN | TcpFlags::ACK));
+        assert!(!be.contains(TcpFlags::SYN | TcpFlags::FIN));
+        assert!(be.intersects(TcpFlags::SYN | TcpFlags::FIN));
+        be.remove(TcpFlags::SYN);
+        be.toggle(TcpFlags::RST);
+        assert_eq!(be.to_native(), TcpFlags::ACK | TcpFlags::RST);
+    }
+
+    #[test]
+    fn operators() {
+        let syn = LittleEndian::from(TcpFlags::SYN);
+        let mut le = syn | TcpFlags::ACK;
+        assert_eq!(le.to_native(), TcpFlags::SYN | TcpFlags::ACK);
+        le -= syn;
+        assert_eq!(le.to_native(), TcpFlags::ACK);
+        le ^= TcpFlags::ACK | TcpFlags::FIN;
+        assert_eq!(le, LittleEndian::from(TcpFlags::FIN));
+        le &= TcpFlags::SYN;
+        assert!(le.is_empty());
+        assert_eq!((!le).to_native(), TcpFlags::all());
+    }
+
+    #[test]
+    fn unknown_bits() {
+        let be: BigEndian<TcpFlags, u16> = BigEndian::from_bits(0x8002u16.to_be());
+        assert_eq!(be.to_native().bits(), 0x8002);
+        assert_eq!(BigEndian::from(be.to_native()), be);
+        assert_eq!(be.try_to_native(), None);
+        assert_eq!(be.to_native_truncate(), TcpFlags::SYN);
+        assert_eq!((!be).to_native(), TcpFlags::all() - TcpFlags::SYN);
+        assert!(BigEndian::from(TcpFlags::ACK).try_to_native().is_some());
+    }
+
+    #[test]
+    fn debug_shows_stored_bytes() {
+        let be = BigEndian::from(TcpFlags::SYN | TcpFlags::ACK);
+        let le = LittleEndian::from(TcpFlags::SYN | TcpFlags::ACK);
+        assert_eq!(format!("{:?}", be), "BigEndian(TcpFlags(SYN | ACK))");
+        assert_eq!(
+            format!("{:#?}", be),
+            "BigEndian(\n    TcpFlags(\n        SYN | ACK,\n    ),\n) [00, 12]"
+        );
+        assert!(format!("{:#?}", le).ends_with(") [12, 00]"));
+    }
+}
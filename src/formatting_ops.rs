@@ -1,66 +1,65 @@
 /*!
-Implementations for formatting the various types.
+Implementations for formatting the various types.  The formatter is passed on to the native value, so width, fill,
+precision and the `#`, `+` and `0` flags work like they do for the native type.
 */
-use core::fmt::{Binary, Display, Formatter, LowerHex, Octal, Result, UpperHex};
+use core::fmt::{
+    Binary, Display, Formatter, LowerExp, LowerHex, Octal, Result, UpperExp, UpperHex,
+};
 
 use super::*;
 
-impl<V: UpperHex + SpecificEndian<B>, B: Copy> UpperHex for BigEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:X}", self.to_native()) // delegate to i32's implementation
-    }
-}
-
-impl<V: UpperHex + SpecificEndian<B>, B: Copy> UpperHex for LittleEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:X}", self.to_native()) // delegate to i32's implementation
-    }
-}
-
-impl<V: LowerHex + SpecificEndian<B>, B: Copy> LowerHex for BigEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:x}", self.to_native()) // delegate to i32's implementation
-    }
-}
-
-impl<V: LowerHex + SpecificEndian<B>, B: Copy> LowerHex for LittleEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:x}", self.to_native()) // delegate to i32's implementation
-    }
-}
+macro_rules! add_fmt {
+    ($fmt_trait:ident) => {
+        impl<V: $fmt_trait + SpecificEndian<B>, B: Copy> $fmt_trait for BigEndian<V, B> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                $fmt_trait::fmt(&self.to_native(), f) // delegate to the native implementation
+            }
+        }
 
-impl<V: Octal + SpecificEndian<B>, B: Copy> Octal for BigEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:o}", self.to_native()) // delegate to i32's implementation
-    }
-}
-
-impl<V: Octal + SpecificEndian<B>, B: Copy> Octal for LittleEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:o}", self.to_native()) // delegate to i32's implementation
-    }
+        impl<V: $fmt_trait + SpecificEndian<B>, B: Copy> $fmt_trait for LittleEndian<V, B> {
+            fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                $fmt_trait::fmt(&self.to_native(), f) // delegate to the native implementation
+            }
+        }
+    };
 }
 
-impl<V: Binary + SpecificEndian<B>, B: Copy> Binary for BigEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:b}", self.to_native()) // delegate to i32's implementation
-    }
-}
+add_fmt!(UpperHex);
+add_fmt!(LowerHex);
+add_fmt!(Octal);
+add_fmt!(Binary);
+add_fmt!(Display);
+add_fmt!(LowerExp);
+add_fmt!(UpperExp);
 
-impl<V: Binary + SpecificEndian<B>, B: Copy> Binary for LittleEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:b}", self.to_native()) // delegate to i32's implementation
-    }
-}
+#[cfg(test)]
+mod tests {
+    use crate::*;
 
-impl<V: Display + SpecificEndian<B>, B: Copy> Display for BigEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.to_native()) // delegate to i32's implementation
+    #[test]
+    fn integer_flags() {
+        let be = u32be::from(0xbeef);
+        let le = i16le::from(-42);
+        assert_eq!(format!("{:08x}", be), "0000beef");
+        assert_eq!(format!("{:#010X}", be), "0x0000BEEF");
+        assert_eq!(format!("{:#o}", be), "0o137357");
+        assert_eq!(format!("{:#b}", BigEndian::<u8>::from(5)), "0b101");
+        assert_eq!(format!("{:>8}", be), "   48879");
+        assert_eq!(format!("{:*<7}", le), "-42****");
+        assert_eq!(format!("{:+}", u16le::from(7)), "+7");
+        assert_eq!(format!("{:06}", le), "-00042");
+        assert_eq!(format!("{:x}", le), "ffd6");
     }
-}
 
-impl<V: Display + SpecificEndian<B>, B: Copy> Display for LittleEndian<V, B> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{}", self.to_native()) // delegate to i32's implementation
+    #[test]
+    fn float_flags() {
+        let be = f64be::from(1234.5);
+        let le = f32le::from(-0.25);
+        assert_eq!(format!("{:.2}", be), "1234.50");
+        assert_eq!(format!("{:10.1}", le), "      -0.2");
+        assert_eq!(format!("{:+}", be), "+1234.5");
+        assert_eq!(format!("{:e}", be), "1.2345e3");
+        assert_eq!(format!("{:E}", le), "-2.5E-1");
+        assert_eq!(format!("{:.2e}", be), "1.23e3");
     }
 }
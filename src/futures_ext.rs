@@ -0,0 +1,106 @@
+//! Async extension traits for `futures::io`, the counterparts of `ReadEndianExt` and `WriteEndianExt`.
+//!
+//! ```rust
+//! use futures_util::io::{AsyncRead, AsyncWrite};
+//! use simple_endian_wrapper::futures_ext::{AsyncReadEndianExt, AsyncWriteEndianExt};
+//! use simple_endian_wrapper::*;
+//!
+//! async fn forward<R, W>(from: &mut R, to: &mut W) -> std::io::Result<()>
+//! where
+//!     R: AsyncRead + Unpin,
+//!     W: AsyncWrite + Unpin,
+//! {
+//!     let len = from.read_endian::<u32be>().await?;
+//!     let mut words = vec![u16le::from(0); len.to_native() as usize];
+//!     from.read_endian_into(&mut words).await?;
+//!     to.write_endian(len).await?;
+//!     to.write_endian_slice(&words).await
+//! }
+//! ```
+//!
+//! The traits aren't exported from the crate root, see the `embedded` module.
+
+use std::io::Result;
+
+use ::futures_util::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use super::*;
+
+/// Reads endian values from any `futures::io::AsyncRead`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncReadEndianExt: AsyncRead + Unpin {
+    /// Reads one value or wire struct, e.g. `stream.read_endian::<u32be>().await`.
+    async fn read_endian<T: EndianBytes>(&mut self) -> Result<T> {
+        let mut v = T::zeroed();
+        self.read_exact(v.as_bytes_mut()).await?;
+        Ok(v)
+    }
+
+    /// Fills `values` directly from the stream.
+    async fn read_endian_into<T: EndianBytes>(&mut self, values: &mut [T]) -> Result<()> {
+        self.read_exact(T::slice_as_bytes_mut(values)).await
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadEndianExt for R {}
+
+/// Writes endian values to any `futures::io::AsyncWrite`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncWriteEndianExt: AsyncWrite + Unpin {
+    /// Writes one value or wire struct, e.g. `stream.write_endian(u16le::from(7)).await`.
+    async fn write_endian<T: EndianBytes>(&mut self, v: T) -> Result<()> {
+        self.write_all(v.as_bytes()).await
+    }
+
+    /// Writes all `values` with a single `write_all`.
+    async fn write_endian_slice<T: EndianBytes>(&mut self, values: &[T]) -> Result<()> {
+        self.write_all(T::slice_as_bytes(values)).await
+    }
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteEndianExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncReadEndianExt, AsyncWriteEndianExt};
+    use crate::test_util::block_on;
+    use crate::*;
+    use futures_util::io::Cursor;
+    use std::io::ErrorKind;
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Frame {
+        id: u32be,
+        value: f32le,
+    }
+
+    unsafe impl EndianStruct for Frame {}
+
+    #[test]
+    fn round_trip() {
+        let frame = Frame {
+            id: 9.into(),
+            value: 1.5.into(),
+        };
+        let mut out = Cursor::new(Vec::new());
+        block_on(async {
+            out.write_endian(frame).await.unwrap();
+            out.write_endian_slice(&[i16be::from(-1), i16be::from(2)])
+                .await
+                .unwrap();
+        });
+        assert_eq!(out.get_ref()[..4], [0, 0, 0, 9]);
+
+        let mut stream = out;
+        stream.set_position(0);
+        block_on(async {
+            assert_eq!(stream.read_endian::<Frame>().await.unwrap(), frame);
+            let mut values = [i16be::from(0); 2];
+            stream.read_endian_into(&mut values).await.unwrap();
+            assert_eq!(values, [i16be::from(-1), i16be::from(2)]);
+            let err = stream.read_endian::<u16le>().await.unwrap_err();
+            assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+        });
+    }
+}
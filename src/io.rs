@@ -0,0 +1,94 @@
+//! Extension traits for `std::io`, to read and write endian values without converting them by hand.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//! use std::io::Cursor;
+//!
+//! let mut out = Vec::new();
+//! out.write_endian(u16le::from(7))?;
+//! out.write_endian_slice(&[u32be::from(1), u32be::from(2)])?;
+//! assert_eq!(out, [7, 0, 0, 0, 0, 1, 0, 0, 0, 2]);
+//!
+//! let mut reader = Cursor::new(out);
+//! assert_eq!(reader.read_endian::<u16le>()?, 7);
+//! let mut words = [u32be::from(0); 2];
+//! reader.read_endian_into(&mut words)?;
+//! assert_eq!(words, [u32be::from(1), u32be::from(2)]);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+//!
+//! The bytes are copied as they are, because the wrappers already store them in the declared byte order.
+
+use std::io::{Read, Result, Write};
+
+use super::*;
+
+/// Reads endian values from any `Read`.
+pub trait ReadEndianExt: Read {
+    /// Reads one value, e.g. `reader.read_endian::<u32be>()`.
+    fn read_endian<T: EndianBytes>(&mut self) -> Result<T> {
+        let mut v = T::zeroed();
+        self.read_exact(v.as_bytes_mut())?;
+        Ok(v)
+    }
+
+    /// Fills `values` directly from the reader.
+    fn read_endian_into<T: EndianBytes>(&mut self, values: &mut [T]) -> Result<()> {
+        self.read_exact(T::slice_as_bytes_mut(values))
+    }
+}
+
+impl<R: Read + ?Sized> ReadEndianExt for R {}
+
+/// Writes endian values to any `Write`.
+pub trait WriteEndianExt: Write {
+    /// Writes one value, e.g. `writer.write_endian(u16le::from(7))`.
+    fn write_endian<T: EndianBytes>(&mut self, v: T) -> Result<()> {
+        self.write_all(v.as_bytes())
+    }
+
+    /// Writes all `values` with a single `write_all`.
+    fn write_endian_slice<T: EndianBytes>(&mut self, values: &[T]) -> Result<()> {
+        self.write_all(T::slice_as_bytes(values))
+    }
+}
+
+impl<W: Write + ?Sized> WriteEndianExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::{Cursor, ErrorKind};
+
+    #[test]
+    fn round_trip() {
+        let mut out = Vec::new();
+        out.write_endian(u64be::from(0x0102030405060708)).unwrap();
+        out.write_endian(f32le::from(-1.5)).unwrap();
+        out.write_endian_slice(&[i16be::from(-1), i16be::from(2)])
+            .unwrap();
+        assert_eq!(out[..8], [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(out[8..12], (-1.5f32).to_le_bytes());
+
+        let mut reader = Cursor::new(&out);
+        assert_eq!(reader.read_endian::<u64be>().unwrap(), 0x0102030405060708);
+        assert_eq!(reader.read_endian::<f32le>().unwrap(), -1.5);
+        let mut values = [i16be::from(0); 2];
+        reader.read_endian_into(&mut values).unwrap();
+        assert_eq!(values, [i16be::from(-1), i16be::from(2)]);
+    }
+
+    #[test]
+    fn short_read() {
+        let mut reader = Cursor::new([1u8, 2, 3]);
+        let err = reader.read_endian::<u32le>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn dyn_reader() {
+        let mut cursor = Cursor::new([0u8, 42]);
+        let reader: &mut dyn std::io::Read = &mut cursor;
+        assert_eq!(reader.read_endian::<u16be>().unwrap(), 42);
+    }
+}
@@ -1,4 +1,4 @@
-#![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 /*!
 Many byte-order-handling libraries focus on providing code to convert to and from big- or little-endian.  However,
 this requires users of those libraries to use a lot of explicit logic.  This library uses the Rust type system to
@@ -97,7 +97,7 @@ fn main() -> Result<(), Error> {
         (*ptr).header = 0xfeedface.into();
 
         // Increment the counter each time we run.
-        (*ptr).count += 1.into();
+        (*ptr).count.update(|count| count + 1);
 
         (*ptr).label = *b"Iamhere!";
     }
@@ -108,6 +108,9 @@ fn main() -> Result<(), Error> {
 ```
 
 */
+#[cfg(feature = "bytes")]
+extern crate alloc;
+
 #[warn(soft_unstable)]
 
 /// The main part of the library.  Contains the trait SpecificEndian<T> and BigEndian<T> and LittleEndian<T> structs, as well as the
@@ -115,6 +118,98 @@ fn main() -> Result<(), Error> {
 mod specific_endian;
 pub use specific_endian::*;
 
+/// Implements the `&T op T`, `T op &T`, `&T op &T` and `T op= &T` forms of a binary operator, given `T op T` and
+/// `T op= T`.  Use `generic` for the generic wrappers, where the operator is bounded by `V: Op<Output = V>`.
+#[allow(unused_macros)]
+macro_rules! forward_ref_binop {
+    (generic $wrap_ty:ident, $imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+        impl<V, B> $imp<$wrap_ty<V, B>> for &$wrap_ty<V, B>
+        where
+            V: $imp<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = $wrap_ty<V, B>;
+
+            fn $method(self, other: $wrap_ty<V, B>) -> $wrap_ty<V, B> {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl<V, B> $imp<&$wrap_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: $imp<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = $wrap_ty<V, B>;
+
+            fn $method(self, other: &$wrap_ty<V, B>) -> $wrap_ty<V, B> {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl<V, B> $imp<&$wrap_ty<V, B>> for &$wrap_ty<V, B>
+        where
+            V: $imp<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = $wrap_ty<V, B>;
+
+            fn $method(self, other: &$wrap_ty<V, B>) -> $wrap_ty<V, B> {
+                $imp::$method(*self, *other)
+            }
+        }
+
+        impl<V, B> $assign_imp<&$wrap_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: $imp<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn $assign_method(&mut self, other: &$wrap_ty<V, B>) {
+                $assign_imp::$assign_method(self, *other);
+            }
+        }
+    };
+    ($wrap_ty:ty, $imp:ident, $method:ident, $assign_imp:ident, $assign_method:ident) => {
+        impl $imp<$wrap_ty> for &$wrap_ty {
+            type Output = $wrap_ty;
+
+            fn $method(self, other: $wrap_ty) -> $wrap_ty {
+                $imp::$method(*self, other)
+            }
+        }
+
+        impl $imp<&$wrap_ty> for $wrap_ty {
+            type Output = $wrap_ty;
+
+            fn $method(self, other: &$wrap_ty) -> $wrap_ty {
+                $imp::$method(self, *other)
+            }
+        }
+
+        impl $imp<&$wrap_ty> for &$wrap_ty {
+            type Output = $wrap_ty;
+
+            fn $method(self, other: &$wrap_ty) -> $wrap_ty {
+                $imp::$method(*self, *other)
+            }
+        }
+
+        impl $assign_imp<&$wrap_ty> for $wrap_ty {
+            fn $assign_method(&mut self, other: &$wrap_ty) {
+                $assign_imp::$assign_method(self, *other);
+            }
+        }
+    };
+}
+
+/// In-place mutation through the native value.
+mod native_mut;
+pub use native_mut::*;
+
+/// Access to the stored bytes.
+mod endian_bytes;
+pub use endian_bytes::*;
+
 /// Bitwise operations.  These should be equally fast in any endian.
 #[cfg(feature = "bitwise")]
 mod bitwise_ops;
@@ -135,6 +230,123 @@ mod math_ops;
 #[cfg(feature = "neg_ops")]
 mod neg_ops;
 
+/// Conversions between wrappers of different types.
+#[cfg(feature = "conversions")]
+mod conversions;
+#[cfg(feature = "conversions")]
+pub use conversions::*;
+
+/// Atomic integers in a specific endian.  The types exist only for the enabled byte orders.
+#[cfg(all(
+    feature = "atomics",
+    any(feature = "big_endian", feature = "little_endian")
+))]
+mod atomic;
+#[cfg(all(
+    feature = "atomics",
+    any(feature = "big_endian", feature = "little_endian")
+))]
+pub use atomic::*;
+
+/// Volatile registers in a specific endian.
+#[cfg(feature = "volatile")]
+mod volatile;
+#[cfg(feature = "volatile")]
+pub use volatile::*;
+
+/// Bitfields over endian wrappers.
+#[cfg(feature = "bitfield")]
+mod bitfield;
+#[cfg(feature = "bitfield")]
+pub use bitfield::*;
+
+/// Support for types generated by `bitflags!`.
+#[cfg(feature = "bitflags")]
+mod flags;
+#[cfg(feature = "bitflags")]
+pub use flags::*;
+
+/// Implementations of the `num-traits` traits.
+#[cfg(feature = "num-traits")]
+mod numeric;
+
+/// A reader and a writer over byte slices.
+#[cfg(feature = "cursor")]
+mod cursor;
+#[cfg(feature = "cursor")]
+pub use cursor::*;
+
+/// Extension traits for `std::io`.
+#[cfg(feature = "std")]
+mod io;
+#[cfg(feature = "std")]
+pub use io::*;
+
+/// Extension traits for `embedded-io`.
+#[cfg(feature = "embedded-io")]
+pub mod embedded;
+
+/// Extension traits for `embedded-io-async`.
+#[cfg(feature = "embedded-io-async")]
+pub mod embedded_async;
+
+/// Async extension traits for `tokio::io`.
+#[cfg(feature = "tokio")]
+pub mod tokio_ext;
+
+/// Async extension traits for `futures::io`.
+#[cfg(feature = "futures")]
+pub mod futures_ext;
+
+/// Extension traits for `bytes`.
+#[cfg(feature = "bytes")]
+mod buf;
+#[cfg(feature = "bytes")]
+pub use buf::*;
+
+/// A `tokio-util` codec for frames with a wire struct header.
+#[cfg(feature = "tokio-util")]
+mod codec;
+#[cfg(feature = "tokio-util")]
+pub use codec::*;
+
+/// Parsers for `nom`.
+#[cfg(feature = "nom")]
+pub mod nom_parsers;
+
+/// Parsers for `winnow`.
+#[cfg(feature = "winnow")]
+pub mod winnow_parsers;
+
+/// Helpers shared by the tests of the async modules.
+#[cfg(all(
+    test,
+    any(
+        feature = "embedded-io-async",
+        feature = "tokio",
+        feature = "futures",
+        feature = "tokio-util"
+    )
+))]
+mod test_util {
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+
+    /// Polls a future that never has to wait, which holds for slices, in-memory streams and duplex pipes with a large
+    /// enough buffer.
+    pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(v) => v,
+            Poll::Pending => panic!("future is pending"),
+        }
+    }
+}
+
 /// Formatter impls.
 #[cfg(feature = "format")]
 mod formatting_ops;
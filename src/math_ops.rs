@@ -1,6 +1,10 @@
 //! The math operations.  These all have some cost because they require conversion to native endian.
 #[allow(unused_imports)]
-use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+use core::borrow::Borrow;
+#[allow(unused_imports)]
+use core::iter::{Product, Sum};
+#[allow(unused_imports)]
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
 
 #[allow(unused_imports)]
 use super::*;
@@ -95,12 +99,276 @@ macro_rules! add_math_ops {
                 *self = *self - other;
             }
         }
+
+        impl<V, B> Rem for $value_ty<V, B>
+        where
+            V: Rem<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn rem(self, other: Self) -> Self {
+                Self::from(self.to_native() % other.to_native())
+            }
+        }
+
+        impl<V, B> RemAssign for $value_ty<V, B>
+        where
+            V: Rem<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn rem_assign(&mut self, other: Self) {
+                *self = *self % other;
+            }
+        }
     };
 }
 
 add_math_ops!(LittleEndian);
 add_math_ops!(BigEndian);
 
+forward_ref_binop!(generic LittleEndian, Add, add, AddAssign, add_assign);
+forward_ref_binop!(generic LittleEndian, Mul, mul, MulAssign, mul_assign);
+forward_ref_binop!(generic LittleEndian, Div, div, DivAssign, div_assign);
+forward_ref_binop!(generic LittleEndian, Sub, sub, SubAssign, sub_assign);
+forward_ref_binop!(generic LittleEndian, Rem, rem, RemAssign, rem_assign);
+forward_ref_binop!(generic BigEndian, Add, add, AddAssign, add_assign);
+forward_ref_binop!(generic BigEndian, Mul, mul, MulAssign, mul_assign);
+forward_ref_binop!(generic BigEndian, Div, div, DivAssign, div_assign);
+forward_ref_binop!(generic BigEndian, Sub, sub, SubAssign, sub_assign);
+forward_ref_binop!(generic BigEndian, Rem, rem, RemAssign, rem_assign);
+
+/// `Sum` and `Product` of owned and borrowed wrappers.  The values are accumulated in native endian and converted once.
+#[allow(unused_macros)]
+macro_rules! add_iter_ops {
+    ($value_ty:ident) => {
+        impl<V, B> Sum for $value_ty<V, B>
+        where
+            V: Sum + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self::from(iter.map(|v| v.to_native()).sum::<V>())
+            }
+        }
+
+        impl<'a, V, B> Sum<&'a Self> for $value_ty<V, B>
+        where
+            V: Sum + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self::from(iter.map(|v| v.to_native()).sum::<V>())
+            }
+        }
+
+        impl<V, B> Product for $value_ty<V, B>
+        where
+            V: Product + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                Self::from(iter.map(|v| v.to_native()).product::<V>())
+            }
+        }
+
+        impl<'a, V, B> Product<&'a Self> for $value_ty<V, B>
+        where
+            V: Product + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self::from(iter.map(|v| v.to_native()).product::<V>())
+            }
+        }
+    };
+}
+
+add_iter_ops!(LittleEndian);
+add_iter_ops!(BigEndian);
+
+/// Sums and products that return `None` on overflow instead of panicking or wrapping.
+#[allow(unused_macros)]
+macro_rules! add_checked_iter_ops {
+    ($wrap_ty:ident, $($int_ty:ty),+) => {
+        $(
+            impl $wrap_ty<$int_ty> {
+                /// Sums the values, or returns `None` on overflow.
+                pub fn checked_sum<I>(iter: I) -> Option<Self>
+                where
+                    I: IntoIterator,
+                    I::Item: Borrow<Self>,
+                {
+                    iter.into_iter()
+                        .try_fold(0 as $int_ty, |acc, v| acc.checked_add(v.borrow().to_native()))
+                        .map(Self::from)
+                }
+                /// Multiplies the values, or returns `None` on overflow.
+                pub fn checked_product<I>(iter: I) -> Option<Self>
+                where
+                    I: IntoIterator,
+                    I::Item: Borrow<Self>,
+                {
+                    iter.into_iter()
+                        .try_fold(1 as $int_ty, |acc, v| acc.checked_mul(v.borrow().to_native()))
+                        .map(Self::from)
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "byte_impls")]
+mod checked_byte_iter_ops {
+    use super::*;
+    #[cfg(feature = "big_endian")]
+    add_checked_iter_ops!(BigEndian, u8, i8);
+    #[cfg(feature = "little_endian")]
+    add_checked_iter_ops!(LittleEndian, u8, i8);
+}
+
+#[cfg(feature = "integer_impls")]
+mod checked_integer_iter_ops {
+    use super::*;
+    #[cfg(feature = "big_endian")]
+    add_checked_iter_ops!(BigEndian, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize);
+    #[cfg(feature = "little_endian")]
+    add_checked_iter_ops!(
+        LittleEndian,
+        u16,
+        i16,
+        u32,
+        i32,
+        u64,
+        i64,
+        u128,
+        i128,
+        usize,
+        isize
+    );
+}
+
+/// Math operations between operands of different endianness.  The result takes the endianness of the left operand.
+#[cfg(feature = "both_endian")]
+macro_rules! add_mixed_math_ops {
+    ($wrap_ty:ident, $other_ty:ident) => {
+        impl<V, B> Add<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Add<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn add(self, other: $other_ty<V, B>) -> Self {
+                Self::from(self.to_native() + other.to_native())
+            }
+        }
+
+        impl<V, B> AddAssign<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Add<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn add_assign(&mut self, other: $other_ty<V, B>) {
+                *self = *self + other;
+            }
+        }
+
+        impl<V, B> Mul<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Mul<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn mul(self, other: $other_ty<V, B>) -> Self {
+                Self::from(self.to_native() * other.to_native())
+            }
+        }
+
+        impl<V, B> MulAssign<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Mul<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn mul_assign(&mut self, other: $other_ty<V, B>) {
+                *self = *self * other;
+            }
+        }
+
+        impl<V, B> Div<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Div<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn div(self, other: $other_ty<V, B>) -> Self {
+                Self::from(self.to_native() / other.to_native())
+            }
+        }
+
+        impl<V, B> DivAssign<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Div<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn div_assign(&mut self, other: $other_ty<V, B>) {
+                *self = *self / other;
+            }
+        }
+
+        impl<V, B> Sub<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Sub<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn sub(self, other: $other_ty<V, B>) -> Self {
+                Self::from(self.to_native() - other.to_native())
+            }
+        }
+
+        impl<V, B> SubAssign<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Sub<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn sub_assign(&mut self, other: $other_ty<V, B>) {
+                *self = *self - other;
+            }
+        }
+
+        impl<V, B> Rem<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Rem<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = Self;
+
+            fn rem(self, other: $other_ty<V, B>) -> Self {
+                Self::from(self.to_native() % other.to_native())
+            }
+        }
+
+        impl<V, B> RemAssign<$other_ty<V, B>> for $wrap_ty<V, B>
+        where
+            V: Rem<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn rem_assign(&mut self, other: $other_ty<V, B>) {
+                *self = *self % other;
+            }
+        }
+    };
+}
+
+#[cfg(feature = "both_endian")]
+add_mixed_math_ops!(BigEndian, LittleEndian);
+#[cfg(feature = "both_endian")]
+add_mixed_math_ops!(LittleEndian, BigEndian);
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -108,35 +376,115 @@ mod tests {
     #[test]
     fn add_fp_be() {
         let mut be1 = f64be::from(1234.5678);
-        be1 += 1.0.into();
-        be1 += 1.0.into();
-        assert_eq!(be1, 1236.5678.into());
+        be1 += f64be::from(1.0);
+        be1 += f64be::from(1.0);
+        assert_eq!(be1, 1236.5678);
     }
 
     #[test]
     fn subtract_fp_be() {
         let mut be1 = f64be::from(1234.5678);
-        be1 -= 1.0.into();
-        be1 -= 1.0.into();
-        assert_eq!(be1, 1232.5678.into());
+        be1 -= f64be::from(1.0);
+        be1 -= f64be::from(1.0);
+        assert_eq!(be1, 1232.5678);
     }
 
     #[test]
     fn mul_fp_be() {
         let mut be1 = f64be::from(1234.5678);
-        be1 *= 10.0.into();
-        be1 *= 10.0.into();
-        assert_eq!(be1, 123456.78.into());
+        be1 *= f64be::from(10.0);
+        be1 *= f64be::from(10.0);
+        assert_eq!(be1, 123456.78);
     }
 
     #[test]
     fn div_fp_be() {
         let mut ne1: f64 = 1234.5678;
         let mut be1 = f64be::from(ne1);
-        be1 /= 10.0.into();
+        be1 /= f64be::from(10.0);
         ne1 /= 10.0;
-        be1 /= 10.0.into();
+        be1 /= f64be::from(10.0);
         ne1 /= 10.0;
-        assert_eq!(ne1, be1.into());
+        assert_eq!(ne1, be1);
+    }
+
+    #[test]
+    fn mixed_endian_ops() {
+        let mut be = u32be::from(100);
+        let le = u32le::from(20);
+        assert_eq!(be - le, 80);
+        assert_eq!(le - u32be::from(5), 15);
+        be *= le;
+        assert_eq!(be, 2000);
+        be /= u32le::from(1000);
+        assert_eq!(be, 2);
+        assert_eq!(u32le::from(17) % u32be::from(5), 2);
+    }
+
+    #[test]
+    fn remainder() {
+        let mut be = i32be::from(-17);
+        assert_eq!(be % i32be::from(5), -2);
+        be %= i32be::from(10);
+        assert_eq!(be, -7);
+        assert_eq!(f64le::from(7.5) % f64le::from(2.0), 1.5);
+    }
+
+    #[test]
+    fn mixed_endian_fp() {
+        let be = f64be::from(1.5);
+        let le = f64le::from(0.25);
+        let sum: f64be = be + le;
+        let quotient: f64le = le / be;
+        assert_eq!(sum, 1.75);
+        assert_eq!(quotient, 0.25 / 1.5);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ref_ops() {
+        let a = u32be::from(12);
+        let b = u32be::from(3);
+        assert_eq!(&a + b, 15);
+        assert_eq!(a - &b, 9);
+        assert_eq!(&a * &b, 36);
+        let mut c = u32le::from(12);
+        c /= &u32le::from(4);
+        assert_eq!(c, 3);
+        let sum = [a, b].iter().fold(u32be::from(0), |acc, x| acc + x);
+        assert_eq!(sum, 15);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let lengths = [u32le::from(20), u32le::from(1480), u32le::from(8)];
+        let total: u32le = lengths.iter().sum();
+        assert_eq!(total, 1508);
+        let total: u32le = lengths.into_iter().sum();
+        assert_eq!(total, 1508);
+        let product: f64be = [f64be::from(1.5), f64be::from(-4.0)].iter().product();
+        assert_eq!(product, -6.0);
+        let empty: [i16be; 0] = [];
+        assert_eq!(empty.iter().sum::<i16be>(), 0);
+        assert_eq!(empty.iter().product::<i16be>(), 1);
+    }
+
+    #[test]
+    fn checked_sum_and_product() {
+        let lengths = [u16be::from(40000), u16be::from(20000)];
+        assert_eq!(u16be::checked_sum(lengths.iter()), Some(u16be::from(60000)));
+        assert_eq!(u16be::checked_sum(lengths.iter().chain(&lengths)), None);
+        assert_eq!(
+            i32le::checked_product([i32le::from(-3), i32le::from(7)]),
+            Some(i32le::from(-21))
+        );
+        assert_eq!(
+            LittleEndian::<u8>::checked_product([LittleEndian::from(16u8); 2]),
+            None
+        );
+        assert_eq!(
+            u64le::checked_sum(Vec::<u64le>::new()),
+            Some(u64le::from(0))
+        );
     }
 }
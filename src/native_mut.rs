@@ -0,0 +1,130 @@
+//! In-place mutation of endian fields through their native value.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//! let mut count = u32be::from(20);
+//! count.update(|v| v * 2 + 1);
+//! {
+//!     let mut native = count.native_mut();
+//!     *native -= 1;
+//! } // written back in big endian here
+//! assert_eq!(count, 40);
+//! ```
+
+use core::ops::{Deref, DerefMut};
+
+use super::*;
+
+macro_rules! add_native_mut {
+    ($wrap_ty:ident, $guard_ty:ident) => {
+        /// Guard returned by `native_mut()`.  Derefs to the native value and writes it back in the declared
+        /// endianness when dropped.
+        pub struct $guard_ty<'a, V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            target: &'a mut $wrap_ty<V, B>,
+            value: V,
+        }
+
+        impl<V, B> Deref for $guard_ty<'_, V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            type Target = V;
+
+            fn deref(&self) -> &V {
+                &self.value
+            }
+        }
+
+        impl<V, B> DerefMut for $guard_ty<'_, V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            fn deref_mut(&mut self) -> &mut V {
+                &mut self.value
+            }
+        }
+
+        impl<V, B> Drop for $guard_ty<'_, V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            fn drop(&mut self) {
+                *self.target = $wrap_ty::from(self.value);
+            }
+        }
+
+        impl<V, B> $wrap_ty<V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            /// Replaces the value with `f` applied to its native value.
+            pub fn update<F: FnOnce(V) -> V>(&mut self, f: F) {
+                *self = Self::from(f(self.to_native()));
+            }
+            /// Returns a guard to mutate the native value, which is converted back when the guard is dropped.
+            pub fn native_mut(&mut self) -> $guard_ty<'_, V, B> {
+                let value = self.to_native();
+                $guard_ty {
+                    target: self,
+                    value,
+                }
+            }
+        }
+    };
+}
+
+add_native_mut!(BigEndian, BigEndianMut);
+add_native_mut!(LittleEndian, LittleEndianMut);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn update_integer() {
+        let mut be = u64be::from(3);
+        be.update(|v| v * 2 + 1);
+        assert_eq!(be, 7);
+        let mut le = i16le::from(-3);
+        le.update(|v| -v);
+        assert_eq!(le, 3);
+    }
+
+    #[test]
+    fn native_mut_float() {
+        let mut be = f64be::from(1.5);
+        {
+            let mut native = be.native_mut();
+            assert_eq!(*native, 1.5);
+            *native *= 4.0;
+            *native += 0.25;
+        }
+        assert_eq!(be, 6.25);
+        assert_eq!(be.to_bits(), 6.25f64.to_bits().to_be());
+    }
+
+    #[test]
+    fn native_mut_struct_field() {
+        #[repr(C)]
+        struct Header {
+            count: u32le,
+            flags: BigEndian<u16>,
+        }
+        let mut header = Header {
+            count: 0.into(),
+            flags: 0x8000.into(),
+        };
+        *header.count.native_mut() += 1;
+        *header.flags.native_mut() |= 1;
+        assert_eq!(header.count, 1);
+        assert_eq!(header.flags.to_bits(), 0x8001u16.to_be());
+    }
+}
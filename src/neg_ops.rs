@@ -15,6 +15,18 @@ macro_rules! add_neg_ops {
                 Self::from(-self.to_native())
             }
         }
+
+        impl<V, B> Neg for &$wrap_ty<V, B>
+        where
+            V: Neg<Output = V> + SpecificEndian<B>,
+            B: Copy,
+        {
+            type Output = $wrap_ty<V, B>;
+
+            fn neg(self) -> $wrap_ty<V, B> {
+                -*self
+            }
+        }
     };
 }
 
@@ -38,4 +50,9 @@ mod tests {
         println!("{}, {}", be1, be2);
         assert_eq!(be2, f64be::from(-1.0));
     }
+    #[test]
+    fn negate_ref() {
+        let le1 = i64le::from(7);
+        assert_eq!(-&le1, -7);
+    }
 }
@@ -0,0 +1,111 @@
+//! `nom` parsers that produce endian wrappers and wire structs directly, instead of native values that have to be
+//! wrapped again.
+//!
+//! ```rust
+//! use nom::{IResult, Parser};
+//! use simple_endian_wrapper::nom_parsers::endian;
+//! use simple_endian_wrapper::*;
+//!
+//! fn chunk(input: &[u8]) -> IResult<&[u8], ([u32be; 2], f32le)> {
+//!     (endian(), endian()).parse(input)
+//! }
+//!
+//! let (rest, ([kind, len], scale)) = chunk(b"IHDR\x00\x00\x00\x0d\x00\x00\x80\x3f!")?;
+//! assert_eq!(kind, u32::from_be_bytes(*b"IHDR"));
+//! assert_eq!(len, 13);
+//! assert_eq!(scale, 1.0);
+//! assert_eq!(rest, b"!");
+//!
+//! let (_, magic) = endian::<u16le, _>().parse(&b"\x4d\x5a"[..])?;
+//! assert_eq!(magic, 0x5a4d);
+//! # Ok::<(), nom::Err<nom::error::Error<&[u8]>>>(())
+//! ```
+//!
+//! Wire structs that implement [`EndianStruct`](crate::EndianStruct) are parsed in one piece, like the array above.
+//! Short input fails with `ErrorKind::Eof` in the error type of the caller, or with `Err::Incomplete` when the parser
+//! is run in streaming mode, like the number parsers of `nom`.
+
+use core::marker::PhantomData;
+
+use ::nom::error::{ErrorKind, ParseError};
+use ::nom::{Err, Input, IsStreaming, Mode, Needed, OutputMode, PResult, Parser};
+
+use super::*;
+
+/// The parser returned by `endian()`.
+pub struct Endian<T, E> {
+    _marker: PhantomData<fn() -> (T, E)>,
+}
+
+/// Parses a wrapper or wire struct, e.g. `endian::<u32be, _>()`.  The error type is usually inferred.
+pub fn endian<T: EndianBytes, E>() -> Endian<T, E> {
+    Endian {
+        _marker: PhantomData,
+    }
+}
+
+impl<I, E, T> Parser<I> for Endian<T, E>
+where
+    I: Input<Item = u8>,
+    E: ParseError<I>,
+    T: EndianBytes,
+{
+    type Output = T;
+    type Error = E;
+
+    fn process<OM: OutputMode>(&mut self, input: I) -> PResult<OM, I, T, E> {
+        let len = input.input_len();
+        if len < T::SIZE {
+            return if OM::Incomplete::is_streaming() {
+                Err(Err::Incomplete(Needed::new(T::SIZE - len)))
+            } else {
+                Err(Err::Error(OM::Error::bind(|| {
+                    E::from_error_kind(input, ErrorKind::Eof)
+                })))
+            };
+        }
+        let v = OM::Output::bind(|| {
+            let mut v = T::zeroed();
+            for (byte, src) in v.as_bytes_mut().iter_mut().zip(input.iter_elements()) {
+                *byte = src;
+            }
+            v
+        });
+        Ok((input.take_from(T::SIZE), v))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::endian;
+    use crate::*;
+    use nom::error::{Error, ErrorKind};
+    use nom::{Err, IResult, Needed, Parser};
+
+    #[test]
+    fn complete() {
+        let input = &[0xff, 0xfe, 1, 0, 2, 0, 3][..];
+        let result: IResult<_, _> = (endian::<i16be, _>(), endian()).parse(input);
+        assert_eq!(
+            result,
+            Ok((
+                &[3][..],
+                (i16be::from(-2), [u16le::from(1), u16le::from(2)])
+            ))
+        );
+
+        let result = endian::<u32be, Error<_>>().parse_complete(&[1u8, 2][..]);
+        assert_eq!(
+            result,
+            Err(Err::Error(Error::new(&[1u8, 2][..], ErrorKind::Eof)))
+        );
+    }
+
+    #[test]
+    fn streaming() {
+        let result = endian::<[f64le; 2], Error<&[u8]>>().parse(&[0; 12][..]);
+        assert_eq!(result, Err(Err::Incomplete(Needed::new(4))));
+        let result = endian::<[f64le; 2], Error<&[u8]>>().parse(&[0; 16][..]);
+        assert_eq!(result, Ok((&[][..], [f64le::from(0.0); 2])));
+    }
+}
@@ -0,0 +1,531 @@
+//! Implementations of the `num-traits` traits, so that the wrappers can be used in code that is generic over
+//! `PrimInt`, `Float` and friends.
+//!
+//! ```rust
+//! use num_traits::PrimInt;
+//! use simple_endian_wrapper::*;
+//!
+//! fn set_bits<T: PrimInt>(values: &[T]) -> u32 {
+//!     values.iter().map(|v| v.count_ones()).sum()
+//! }
+//!
+//! let samples = [i16be::from(-1), i16be::from(3)];
+//! assert_eq!(set_bits(&samples), 18);
+//! ```
+//!
+//! Every operation converts to native endian, applies the operation of the native type and converts the result back.
+//! `Float` needs the `std` feature, because `num-traits` only provides it with `std`.  `FloatCore` is always
+//! available.
+
+use core::num::FpCategory;
+use core::ops::{BitAnd, BitOr, BitXor, Not, Shl, Shr};
+
+use num_traits::float::FloatCore;
+#[cfg(feature = "std")]
+use num_traits::Float;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr,
+    CheckedSub, FromPrimitive, Num, One, PrimInt, Saturating, Signed, ToPrimitive, Unsigned, Zero,
+};
+
+use super::*;
+
+/// Associated functions without arguments that return `Self`.
+macro_rules! delegate_consts {
+    ($($method:ident),+) => {
+        $(
+            fn $method() -> Self {
+                Self::from(V::$method())
+            }
+        )+
+    };
+}
+
+/// Methods taking `self` by value and returning `Self`.
+macro_rules! delegate_unary {
+    ($($method:ident),+) => {
+        $(
+            fn $method(self) -> Self {
+                Self::from(self.to_native().$method())
+            }
+        )+
+    };
+}
+
+/// Methods taking `self` and another `Self` by value and returning `Self`.
+macro_rules! delegate_binary {
+    ($($method:ident),+) => {
+        $(
+            fn $method(self, other: Self) -> Self {
+                Self::from(self.to_native().$method(other.to_native()))
+            }
+        )+
+    };
+}
+
+/// Methods taking `self` by value and returning something other than `Self`.
+macro_rules! delegate_query {
+    ($($method:ident -> $ret:ty),+) => {
+        $(
+            fn $method(self) -> $ret {
+                self.to_native().$method()
+            }
+        )+
+    };
+}
+
+/// Checked operations taking both operands by reference.
+macro_rules! delegate_checked {
+    ($wrap_ty:ident, $($checked:ident::$method:ident),+) => {
+        $(
+            impl<V, B> $checked for $wrap_ty<V, B>
+            where
+                V: $checked + SpecificEndian<B>,
+                B: Copy,
+            {
+                fn $method(&self, v: &Self) -> Option<Self> {
+                    self.to_native().$method(&v.to_native()).map(Self::from)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! add_num_traits {
+    ($wrap_ty:ident) => {
+        impl<V, B> Zero for $wrap_ty<V, B>
+        where
+            V: Zero + SpecificEndian<B>,
+            B: Copy,
+        {
+            delegate_consts!(zero);
+
+            fn is_zero(&self) -> bool {
+                self.to_native().is_zero()
+            }
+        }
+
+        impl<V, B> One for $wrap_ty<V, B>
+        where
+            V: One + SpecificEndian<B>,
+            B: Copy,
+        {
+            delegate_consts!(one);
+        }
+
+        impl<V, B> Num for $wrap_ty<V, B>
+        where
+            V: Num + EndianRepr<B>,
+            B: Copy,
+        {
+            type FromStrRadixErr = V::FromStrRadixErr;
+
+            fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+                V::from_str_radix(str, radix).map(Self::from)
+            }
+        }
+
+        impl<V, B> Bounded for $wrap_ty<V, B>
+        where
+            V: Bounded + SpecificEndian<B>,
+            B: Copy,
+        {
+            delegate_consts!(min_value, max_value);
+        }
+
+        impl<V, B> ToPrimitive for $wrap_ty<V, B>
+        where
+            V: ToPrimitive + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn to_isize(&self) -> Option<isize> {
+                self.to_native().to_isize()
+            }
+            fn to_i8(&self) -> Option<i8> {
+                self.to_native().to_i8()
+            }
+            fn to_i16(&self) -> Option<i16> {
+                self.to_native().to_i16()
+            }
+            fn to_i32(&self) -> Option<i32> {
+                self.to_native().to_i32()
+            }
+            fn to_i64(&self) -> Option<i64> {
+                self.to_native().to_i64()
+            }
+            fn to_i128(&self) -> Option<i128> {
+                self.to_native().to_i128()
+            }
+            fn to_usize(&self) -> Option<usize> {
+                self.to_native().to_usize()
+            }
+            fn to_u8(&self) -> Option<u8> {
+                self.to_native().to_u8()
+            }
+            fn to_u16(&self) -> Option<u16> {
+                self.to_native().to_u16()
+            }
+            fn to_u32(&self) -> Option<u32> {
+                self.to_native().to_u32()
+            }
+            fn to_u64(&self) -> Option<u64> {
+                self.to_native().to_u64()
+            }
+            fn to_u128(&self) -> Option<u128> {
+                self.to_native().to_u128()
+            }
+            fn to_f32(&self) -> Option<f32> {
+                self.to_native().to_f32()
+            }
+            fn to_f64(&self) -> Option<f64> {
+                self.to_native().to_f64()
+            }
+        }
+
+        impl<V, B> FromPrimitive for $wrap_ty<V, B>
+        where
+            V: FromPrimitive + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn from_isize(n: isize) -> Option<Self> {
+                V::from_isize(n).map(Self::from)
+            }
+            fn from_i8(n: i8) -> Option<Self> {
+                V::from_i8(n).map(Self::from)
+            }
+            fn from_i16(n: i16) -> Option<Self> {
+                V::from_i16(n).map(Self::from)
+            }
+            fn from_i32(n: i32) -> Option<Self> {
+                V::from_i32(n).map(Self::from)
+            }
+            fn from_i64(n: i64) -> Option<Self> {
+                V::from_i64(n).map(Self::from)
+            }
+            fn from_i128(n: i128) -> Option<Self> {
+                V::from_i128(n).map(Self::from)
+            }
+            fn from_usize(n: usize) -> Option<Self> {
+                V::from_usize(n).map(Self::from)
+            }
+            fn from_u8(n: u8) -> Option<Self> {
+                V::from_u8(n).map(Self::from)
+            }
+            fn from_u16(n: u16) -> Option<Self> {
+                V::from_u16(n).map(Self::from)
+            }
+            fn from_u32(n: u32) -> Option<Self> {
+                V::from_u32(n).map(Self::from)
+            }
+            fn from_u64(n: u64) -> Option<Self> {
+                V::from_u64(n).map(Self::from)
+            }
+            fn from_u128(n: u128) -> Option<Self> {
+                V::from_u128(n).map(Self::from)
+            }
+            fn from_f32(n: f32) -> Option<Self> {
+                V::from_f32(n).map(Self::from)
+            }
+            fn from_f64(n: f64) -> Option<Self> {
+                V::from_f64(n).map(Self::from)
+            }
+        }
+
+        // `NumCast` isn't imported, because its `from()` would make `Self::from` ambiguous.
+        impl<V, B> num_traits::NumCast for $wrap_ty<V, B>
+        where
+            V: num_traits::NumCast + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+                <V as num_traits::NumCast>::from(n).map(<Self as From<V>>::from)
+            }
+        }
+
+        impl<V, B> Saturating for $wrap_ty<V, B>
+        where
+            V: Saturating + SpecificEndian<B>,
+            B: Copy,
+        {
+            delegate_binary!(saturating_add, saturating_sub);
+        }
+
+        delegate_checked!(
+            $wrap_ty,
+            CheckedAdd::checked_add,
+            CheckedSub::checked_sub,
+            CheckedMul::checked_mul,
+            CheckedDiv::checked_div,
+            CheckedRem::checked_rem
+        );
+
+        impl<V, B> CheckedNeg for $wrap_ty<V, B>
+        where
+            V: CheckedNeg + SpecificEndian<B>,
+            B: Copy,
+        {
+            fn checked_neg(&self) -> Option<Self> {
+                self.to_native().checked_neg().map(Self::from)
+            }
+        }
+
+        impl<V, B> CheckedShl for $wrap_ty<V, B>
+        where
+            V: CheckedShl + SpecificEndian<B>,
+            B: Copy,
+            Self: Shl<u32, Output = Self>,
+        {
+            fn checked_shl(&self, rhs: u32) -> Option<Self> {
+                self.to_native().checked_shl(rhs).map(Self::from)
+            }
+        }
+
+        impl<V, B> CheckedShr for $wrap_ty<V, B>
+        where
+            V: CheckedShr + SpecificEndian<B>,
+            B: Copy,
+            Self: Shr<u32, Output = Self>,
+        {
+            fn checked_shr(&self, rhs: u32) -> Option<Self> {
+                self.to_native().checked_shr(rhs).map(Self::from)
+            }
+        }
+
+        impl<V, B> Signed for $wrap_ty<V, B>
+        where
+            V: Signed + EndianRepr<B>,
+            B: Copy,
+        {
+            fn abs(&self) -> Self {
+                Self::from(self.to_native().abs())
+            }
+            fn abs_sub(&self, other: &Self) -> Self {
+                Self::from(self.to_native().abs_sub(&other.to_native()))
+            }
+            fn signum(&self) -> Self {
+                Self::from(self.to_native().signum())
+            }
+            fn is_positive(&self) -> bool {
+                self.to_native().is_positive()
+            }
+            fn is_negative(&self) -> bool {
+                self.to_native().is_negative()
+            }
+        }
+
+        impl<V, B> Unsigned for $wrap_ty<V, B>
+        where
+            V: Unsigned + EndianRepr<B>,
+            B: Copy,
+        {
+        }
+
+        impl<V, B> PrimInt for $wrap_ty<V, B>
+        where
+            V: PrimInt + EndianRepr<B>,
+            B: Copy,
+            Self: Not<Output = Self>
+                + BitAnd<Output = Self>
+                + BitOr<Output = Self>
+                + BitXor<Output = Self>
+                + Shl<usize, Output = Self>
+                + Shr<usize, Output = Self>,
+        {
+            delegate_query!(
+                count_ones -> u32,
+                count_zeros -> u32,
+                leading_ones -> u32,
+                leading_zeros -> u32,
+                trailing_ones -> u32,
+                trailing_zeros -> u32
+            );
+            delegate_unary!(swap_bytes, reverse_bits, to_be, to_le);
+
+            fn rotate_left(self, n: u32) -> Self {
+                Self::from(self.to_native().rotate_left(n))
+            }
+            fn rotate_right(self, n: u32) -> Self {
+                Self::from(self.to_native().rotate_right(n))
+            }
+            fn signed_shl(self, n: u32) -> Self {
+                Self::from(self.to_native().signed_shl(n))
+            }
+            fn signed_shr(self, n: u32) -> Self {
+                Self::from(self.to_native().signed_shr(n))
+            }
+            fn unsigned_shl(self, n: u32) -> Self {
+                Self::from(self.to_native().unsigned_shl(n))
+            }
+            fn unsigned_shr(self, n: u32) -> Self {
+                Self::from(self.to_native().unsigned_shr(n))
+            }
+            fn from_be(x: Self) -> Self {
+                Self::from(V::from_be(x.to_native()))
+            }
+            fn from_le(x: Self) -> Self {
+                Self::from(V::from_le(x.to_native()))
+            }
+            fn pow(self, exp: u32) -> Self {
+                Self::from(self.to_native().pow(exp))
+            }
+        }
+
+        impl<V, B> FloatCore for $wrap_ty<V, B>
+        where
+            V: FloatCore + EndianRepr<B>,
+            B: Copy,
+        {
+            delegate_consts!(
+                infinity,
+                neg_infinity,
+                nan,
+                neg_zero,
+                min_value,
+                min_positive_value,
+                epsilon,
+                max_value
+            );
+            delegate_unary!(to_degrees, to_radians);
+            delegate_query!(classify -> FpCategory, integer_decode -> (u64, i16, i8));
+        }
+
+        #[cfg(feature = "std")]
+        impl<V, B> Float for $wrap_ty<V, B>
+        where
+            V: Float + EndianRepr<B>,
+            B: Copy,
+        {
+            delegate_consts!(
+                nan,
+                infinity,
+                neg_infinity,
+                neg_zero,
+                min_value,
+                min_positive_value,
+                max_value,
+                epsilon
+            );
+            delegate_unary!(
+                floor, ceil, round, trunc, fract, abs, signum, recip, sqrt, exp, exp2, ln, log2,
+                log10, cbrt, sin, cos, tan, asin, acos, atan, exp_m1, ln_1p, sinh, cosh, tanh,
+                asinh, acosh, atanh, to_degrees, to_radians
+            );
+            delegate_binary!(powf, log, max, min, abs_sub, hypot, atan2, copysign);
+            delegate_query!(
+                is_nan -> bool,
+                is_infinite -> bool,
+                is_finite -> bool,
+                is_normal -> bool,
+                is_subnormal -> bool,
+                is_sign_positive -> bool,
+                is_sign_negative -> bool,
+                classify -> FpCategory,
+                integer_decode -> (u64, i16, i8)
+            );
+
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Self::from(self.to_native().mul_add(a.to_native(), b.to_native()))
+            }
+            fn powi(self, n: i32) -> Self {
+                Self::from(self.to_native().powi(n))
+            }
+            fn sin_cos(self) -> (Self, Self) {
+                let (sin, cos) = self.to_native().sin_cos();
+                (Self::from(sin), Self::from(cos))
+            }
+        }
+    };
+}
+
+add_num_traits!(BigEndian);
+add_num_traits!(LittleEndian);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use num_traits::float::FloatCore;
+    use num_traits::{
+        Bounded, CheckedAdd, CheckedShl, FromPrimitive, Num, One, PrimInt, Signed, ToPrimitive,
+        Unsigned, Zero,
+    };
+
+    fn sum_of_squares<T: PrimInt>(values: &[T]) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |acc, &v| acc.checked_add(&v.checked_mul(&v)?))
+    }
+
+    fn unsigned_max<T: Unsigned + Bounded>() -> T {
+        T::max_value()
+    }
+
+    #[test]
+    fn integers() {
+        let samples = [i16be::from(-3), i16be::from(4)];
+        assert_eq!(sum_of_squares(&samples), Some(i16be::from(25)));
+        assert_eq!(sum_of_squares(&[i16be::from(200)]), None);
+        assert_eq!(unsigned_max::<u32le>(), u32::MAX);
+        assert!(u64be::zero().is_zero());
+        assert_eq!(u64be::one(), 1);
+        assert_eq!(
+            <u16le as Num>::from_str_radix("ff", 16),
+            Ok(u16le::from(0xff))
+        );
+        assert_eq!(i32be::from(-8).abs(), 8);
+        assert!(i32be::from(-8).is_negative());
+        assert_eq!(u32be::from(0x0f00).leading_zeros(), 20);
+        assert_eq!(u32le::from(1).rotate_right(1), 0x8000_0000);
+        assert_eq!(u16be::from(0x1234).swap_bytes(), 0x3412);
+        assert_eq!(CheckedShl::checked_shl(&BigEndian::<u8>::from(1), 8), None);
+        assert_eq!(
+            CheckedShl::checked_shl(&u32be::from(1), 31),
+            Some(u32be::from(1 << 31))
+        );
+        assert_eq!(u32le::from(7).checked_add(&u32le::from(u32::MAX)), None);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(BigEndian::<u8>::from_i32(300), None);
+        assert_eq!(i64le::from_f64(-2.5), Some(i64le::from(-2)));
+        assert_eq!(
+            <u16be as num_traits::NumCast>::from(1000.0f32),
+            Some(u16be::from(1000))
+        );
+        assert_eq!(u32be::from(70000).to_u16(), None);
+        assert_eq!(f32le::from(1.5).to_f64(), Some(1.5));
+    }
+
+    #[test]
+    fn floats() {
+        assert!(<f64be as FloatCore>::nan().is_nan());
+        assert_eq!(<f32le as FloatCore>::max_value(), f32::MAX);
+        assert_eq!(
+            FloatCore::integer_decode(f64be::from(1.0)),
+            FloatCore::integer_decode(1.0f64)
+        );
+        assert_eq!(f32be::from(-2.0).signum(), -1.0);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn float_generic() {
+        use num_traits::Float;
+
+        fn rms<T: Float>(values: &[T]) -> T {
+            let sum = values.iter().fold(T::zero(), |acc, &v| acc + v * v);
+            (sum / T::from(values.len()).unwrap()).sqrt()
+        }
+
+        let samples = [f32le::from(3.0), f32le::from(-3.0)];
+        assert_eq!(rms(&samples), 3.0);
+        assert_eq!(
+            Float::mul_add(f64be::from(2.0), f64be::from(3.0), f64be::from(1.0)),
+            7.0
+        );
+        assert_eq!(
+            Float::sin_cos(f64be::from(0.0)),
+            (f64be::from(0.0), f64be::from(1.0))
+        );
+    }
+}
@@ -33,6 +33,37 @@ macro_rules! add_shift_ops {
                 *self = Self::from((*self).to_native() >> rhs.to_native());
             }
         }
+        forward_ref_binop!($wrap_ty, Shl, shl, ShlAssign, shl_assign);
+        forward_ref_binop!($wrap_ty, Shr, shr, ShrAssign, shr_assign);
+        add_shift_ops!($wrap_ty, u32);
+        add_shift_ops!($wrap_ty, usize);
+    };
+    // Shifts by a native amount, like `rotate_left()` and `checked_shl()` take.
+    ($wrap_ty:ty, $amount_ty:ty) => {
+        impl Shl<$amount_ty> for $wrap_ty {
+            type Output = Self;
+
+            fn shl(self, amount: $amount_ty) -> Self {
+                Self::from(self.to_native() << amount)
+            }
+        }
+        impl ShlAssign<$amount_ty> for $wrap_ty {
+            fn shl_assign(&mut self, amount: $amount_ty) {
+                *self = *self << amount;
+            }
+        }
+        impl Shr<$amount_ty> for $wrap_ty {
+            type Output = Self;
+
+            fn shr(self, amount: $amount_ty) -> Self {
+                Self::from(self.to_native() >> amount)
+            }
+        }
+        impl ShrAssign<$amount_ty> for $wrap_ty {
+            fn shr_assign(&mut self, amount: $amount_ty) {
+                *self = *self >> amount;
+            }
+        }
     };
 }
 
@@ -96,21 +127,45 @@ mod tests {
     fn shl_be() {
         let mut ne1 = 0xfee1;
         let mut be1 = u64be::from(ne1);
-        be1 <<= 5.into();
+        be1 <<= u64be::from(5);
         ne1 <<= 5;
-        be1 <<= 5.into();
+        be1 <<= u64be::from(5);
         ne1 <<= 5;
-        assert_eq!(ne1, be1.into());
+        assert_eq!(ne1, be1);
     }
 
     #[test]
     fn shr_be() {
         let mut ne1 = 0xfee1;
         let mut be1 = u64be::from(ne1);
-        be1 >>= 5.into();
+        be1 >>= u64be::from(5);
         ne1 >>= 5;
-        be1 >>= 5.into();
+        be1 >>= u64be::from(5);
         ne1 >>= 5;
-        assert_eq!(ne1, be1.into());
+        assert_eq!(ne1, be1);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn ref_shift() {
+        let mut be1 = u32be::from(0xf0);
+        let amount = u32be::from(4);
+        assert_eq!(&be1 << amount, 0xf00);
+        assert_eq!(be1 >> &amount, 0xf);
+        assert_eq!(&be1 << &amount, 0xf00);
+        be1 >>= &amount;
+        assert_eq!(be1, 0xf);
+    }
+
+    #[test]
+    fn shift_by_native() {
+        let mut le = i16le::from(-0x100);
+        assert_eq!(le >> 4u32, -0x10);
+        assert_eq!(le << 2usize, -0x400);
+        le >>= 8usize;
+        assert_eq!(le, -1);
+        let mut be = BigEndian::<u8>::from(0x81);
+        be <<= 1u32;
+        assert_eq!(be, 0x02);
     }
 }
@@ -1,4 +1,7 @@
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
 use core::marker::PhantomData;
+use core::str::FromStr;
 
 /// Any object implementing `SpecificEndian<T>` can be converted between big and little endian.  Implement this trait to allow for endian conversion by this crate.
 pub trait SpecificEndian<T>
@@ -12,6 +15,102 @@ where
     fn from_little_endian(value: T) -> Self;
 }
 
+/// How the wrappers compare, hash and show values stored as `T`.  `BigEndian<Self, T>` and `LittleEndian<Self, T>`
+/// implement `PartialEq`, `Eq`, `Hash` and `Debug` for types that implement this next to `SpecificEndian<T>`.  The
+/// defaults work on the native value, so an empty impl is enough:
+///
+/// ```rust
+/// use simple_endian_wrapper::*;
+///
+/// /// Every non-zero byte reads as `Flag(true)`.
+/// #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+/// struct Flag(bool);
+///
+/// impl SpecificEndian<u8> for Flag {
+///     fn to_big_endian(&self) -> u8 {
+///         self.0 as u8
+///     }
+///     fn to_little_endian(&self) -> u8 {
+///         self.0 as u8
+///     }
+///     fn from_big_endian(value: u8) -> Self {
+///         Flag(value != 0)
+///     }
+///     fn from_little_endian(value: u8) -> Self {
+///         Flag(value != 0)
+///     }
+/// }
+/// impl EndianRepr<u8> for Flag {}
+///
+/// assert_eq!(BigEndian::<Flag, u8>::from_bits(1), BigEndian::from_bits(2));
+/// ```
+pub trait EndianRepr<T: Copy>: SpecificEndian<T> {
+    /// Compares two values stored in big-endian form.  The default converts both to native first; override it
+    /// if equality of the raw bits implies equality of the values.
+    fn eq_big_endian(a: &T, b: &T) -> bool
+    where
+        Self: PartialEq,
+    {
+        Self::from_big_endian(*a) == Self::from_big_endian(*b)
+    }
+    /// Compares two values stored in little-endian form.  The default converts both to native first; override it
+    /// if equality of the raw bits implies equality of the values.
+    fn eq_little_endian(a: &T, b: &T) -> bool
+    where
+        Self: PartialEq,
+    {
+        Self::from_little_endian(*a) == Self::from_little_endian(*b)
+    }
+
+    /// Hashes a value stored in big-endian form.  The default hashes the native value converted back to its stored
+    /// form, which is the same for values that compare equal, so `Self` doesn't have to implement `Hash`.  Override it
+    /// together with `eq_big_endian` if the raw bits can be hashed as they are.
+    fn hash_big_endian<H: Hasher>(value: &T, state: &mut H)
+    where
+        T: Hash,
+    {
+        Self::from_big_endian(*value).to_big_endian().hash(state)
+    }
+    /// Hashes a value stored in little-endian form, see `hash_big_endian`.
+    fn hash_little_endian<H: Hasher>(value: &T, state: &mut H)
+    where
+        T: Hash,
+    {
+        Self::from_little_endian(*value)
+            .to_little_endian()
+            .hash(state)
+    }
+    /// Hashes a slice of values stored in big-endian form, see `hash_big_endian`.
+    fn hash_slice_big_endian<H: Hasher>(values: &[T], state: &mut H)
+    where
+        T: Hash,
+    {
+        for value in values {
+            Self::hash_big_endian(value, state);
+        }
+    }
+    /// Hashes a slice of values stored in little-endian form, see `hash_little_endian`.
+    fn hash_slice_little_endian<H: Hasher>(values: &[T], state: &mut H)
+    where
+        T: Hash,
+    {
+        for value in values {
+            Self::hash_little_endian(value, state);
+        }
+    }
+
+    /// The type name that `Debug` writes after the native value, e.g. `u32` in `254_u32be`.  The default is `None`,
+    /// which writes the wrapper like a tuple struct instead.
+    const DEBUG_NAME: Option<&'static str> = None;
+
+    /// Writes the bytes of a stored value in memory order, for the alternate `Debug` form, e.g. ` [00, 00, 00, fe]`.
+    /// The default writes nothing.  Types that are stored as a primitive can forward to its implementation.
+    fn fmt_stored_bytes(value: &T, f: &mut Formatter<'_>) -> fmt::Result {
+        let _ = (value, f);
+        Ok(())
+    }
+}
+
 #[cfg(feature = "byte_impls")]
 mod byte_impls {
     use super::*;
@@ -32,6 +131,33 @@ mod byte_impls {
                     value
                 }
             }
+
+            impl EndianRepr<$wrap_ty> for $wrap_ty {
+                fn eq_big_endian(a: &Self, b: &Self) -> bool {
+                    a == b
+                }
+                fn eq_little_endian(a: &Self, b: &Self) -> bool {
+                    a == b
+                }
+                fn hash_big_endian<H: Hasher>(value: &Self, state: &mut H) {
+                    value.hash(state)
+                }
+                fn hash_little_endian<H: Hasher>(value: &Self, state: &mut H) {
+                    value.hash(state)
+                }
+                fn hash_slice_big_endian<H: Hasher>(values: &[Self], state: &mut H) {
+                    Self::hash_slice(values, state)
+                }
+                fn hash_slice_little_endian<H: Hasher>(values: &[Self], state: &mut H) {
+                    Self::hash_slice(values, state)
+                }
+
+                const DEBUG_NAME: Option<&'static str> = Some(stringify!($wrap_ty));
+
+                fn fmt_stored_bytes(value: &Self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, " [{:02x}]", *value as u8)
+                }
+            }
         };
     }
 
@@ -61,6 +187,34 @@ mod integer_impls {
                     Self::from_le(value)
                 }
             }
+
+            // The byte swap is a bijection, so the raw bits can be compared and hashed directly.
+            impl EndianRepr<$wrap_ty> for $wrap_ty {
+                fn eq_big_endian(a: &Self, b: &Self) -> bool {
+                    a == b
+                }
+                fn eq_little_endian(a: &Self, b: &Self) -> bool {
+                    a == b
+                }
+                fn hash_big_endian<H: Hasher>(value: &Self, state: &mut H) {
+                    value.hash(state)
+                }
+                fn hash_little_endian<H: Hasher>(value: &Self, state: &mut H) {
+                    value.hash(state)
+                }
+                fn hash_slice_big_endian<H: Hasher>(values: &[Self], state: &mut H) {
+                    Self::hash_slice(values, state)
+                }
+                fn hash_slice_little_endian<H: Hasher>(values: &[Self], state: &mut H) {
+                    Self::hash_slice(values, state)
+                }
+
+                const DEBUG_NAME: Option<&'static str> = Some(stringify!($wrap_ty));
+
+                fn fmt_stored_bytes(value: &Self, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, " {:02x?}", value.to_ne_bytes())
+                }
+            }
         };
     }
 
@@ -119,6 +273,38 @@ mod non_zero {
                     }
                 }
             }
+
+            impl EndianRepr<$wrap_ty> for $wrap_ty {
+                fn eq_big_endian(a: &$wrap_ty, b: &$wrap_ty) -> bool {
+                    a == b
+                }
+
+                fn eq_little_endian(a: &$wrap_ty, b: &$wrap_ty) -> bool {
+                    a == b
+                }
+
+                fn hash_big_endian<H: Hasher>(value: &$wrap_ty, state: &mut H) {
+                    value.hash(state)
+                }
+
+                fn hash_little_endian<H: Hasher>(value: &$wrap_ty, state: &mut H) {
+                    value.hash(state)
+                }
+
+                fn hash_slice_big_endian<H: Hasher>(values: &[$wrap_ty], state: &mut H) {
+                    <$wrap_ty>::hash_slice(values, state)
+                }
+
+                fn hash_slice_little_endian<H: Hasher>(values: &[$wrap_ty], state: &mut H) {
+                    <$wrap_ty>::hash_slice(values, state)
+                }
+
+                const DEBUG_NAME: Option<&'static str> = Some(stringify!($wrap_ty));
+
+                fn fmt_stored_bytes(value: &$wrap_ty, f: &mut Formatter<'_>) -> fmt::Result {
+                    write!(f, " {:02x?}", value.get().to_ne_bytes())
+                }
+            }
         };
     }
 
@@ -138,6 +324,8 @@ mod non_zero {
 mod float_impls {
     use super::*;
     /// Uses .from_bits() and .to_bits() to implement SpecificEndian<T> with Integer types.  Can be used with any type having these methods, but mainly for use with the floats.
+    ///
+    /// The default `EndianRepr` implementations are kept, so that NaN and signed zeros compare like the native floats.
     macro_rules! make_specific_endian_float {
         ($value_ty:ty, $bit_ty:ty) => {
             impl SpecificEndian<$bit_ty> for $value_ty {
@@ -154,6 +342,14 @@ mod float_impls {
                     <$value_ty>::from_bits(<$bit_ty>::from_little_endian(value))
                 }
             }
+
+            impl EndianRepr<$bit_ty> for $value_ty {
+                const DEBUG_NAME: Option<&'static str> = Some(stringify!($value_ty));
+
+                fn fmt_stored_bytes(value: &$bit_ty, f: &mut Formatter<'_>) -> fmt::Result {
+                    <$bit_ty>::fmt_stored_bytes(value, f)
+                }
+            }
         };
     }
 
@@ -161,8 +357,30 @@ mod float_impls {
     make_specific_endian_float!(f64, u64);
 }
 
+/// Writes the native value with a marker for the endianness, e.g. `254_u32be`, for types with a `DEBUG_NAME`, and
+/// like a tuple struct otherwise.  The alternate form adds the stored bytes in memory order, see `fmt_stored_bytes`.
+fn fmt_debug<V: EndianRepr<B> + Debug, B: Copy>(
+    value: &V,
+    bits: &B,
+    wrapper: &str,
+    marker: &str,
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    match V::DEBUG_NAME {
+        Some(name) => {
+            Debug::fmt(value, f)?;
+            write!(f, "_{}{}", name, marker)?;
+        }
+        None => f.debug_tuple(wrapper).field(value).finish()?,
+    }
+    if f.alternate() {
+        V::fmt_stored_bytes(bits, f)?;
+    }
+    Ok(())
+}
+
 /// A big-endian representation of type `B` that implements `SpecificEndian<B>`.  Data stored in the struct must be converted to big-endian using `::from()` or `.into()`.
-#[derive(Copy, Clone, Debug, Default, Hash)]
+#[derive(Copy, Clone, Default)]
 #[repr(transparent)]
 pub struct BigEndian<V: SpecificEndian<B>, B: Copy = V>(pub(crate) B, pub(crate) PhantomData<V>);
 
@@ -179,6 +397,11 @@ where
     pub const fn from_bits(v: B) -> Self {
         Self(v, PhantomData)
     }
+    /// Reinterprets the raw data as another type with the same bit type `B`, e.g. `f32be` as `u32be`.  No bytes are
+    /// swapped, so this is free and keeps every bit, including NaN payloads.
+    pub const fn reinterpret<W: SpecificEndian<B>>(&self) -> BigEndian<W, B> {
+        BigEndian(self.0, PhantomData)
+    }
     /// Converts the data to the same type B in host-native endian.
     pub fn to_native(&self) -> V {
         V::from_big_endian(self.0)
@@ -191,15 +414,44 @@ impl<V: SpecificEndian<B>, B: Copy> From<V> for BigEndian<V, B> {
     }
 }
 
-impl<V: SpecificEndian<B> + PartialEq, B: Copy> PartialEq for BigEndian<V, B> {
+/// Parses the native value, e.g. `"1500".parse::<u16be>()`.
+impl<V: SpecificEndian<B> + FromStr, B: Copy> FromStr for BigEndian<V, B> {
+    type Err = V::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        V::from_str(s).map(Self::from)
+    }
+}
+
+impl<V: EndianRepr<B> + PartialEq, B: Copy> PartialEq for BigEndian<V, B> {
     fn eq(&self, other: &Self) -> bool {
-        self.to_native() == other.to_native()
+        V::eq_big_endian(&self.0, &other.0)
+    }
+}
+impl<V: EndianRepr<B> + Eq, B: Copy> Eq for BigEndian<V, B> {}
+
+/// Hashes consistently with `PartialEq`, see `EndianRepr::hash_big_endian`.  `V: Eq` leaves out the floats, whose
+/// signed zeros compare equal but have different bits.
+impl<V: EndianRepr<B> + Eq, B: Copy + Hash> Hash for BigEndian<V, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        V::hash_big_endian(&self.0, state)
+    }
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        // Safety: BigEndian<V, B> is repr(transparent) over B.
+        let bits = unsafe { core::slice::from_raw_parts(data.as_ptr() as *const B, data.len()) };
+        V::hash_slice_big_endian(bits, state)
+    }
+}
+
+/// Shows the native value, see `fmt_debug`.
+impl<V: EndianRepr<B> + Debug, B: Copy> Debug for BigEndian<V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_debug(&self.to_native(), &self.0, "BigEndian", "be", f)
     }
 }
-impl<V: SpecificEndian<B> + Eq, B: Copy> Eq for BigEndian<V, B> {}
 
 /// A little-endian representation of type `T` that implements `SpecificEndian<T>`.  Data stored in the struct must be converted to little-endian using `::from()` or `.into()`.
-#[derive(Copy, Clone, Debug, Default, Hash)]
+#[derive(Copy, Clone, Default)]
 #[repr(transparent)]
 pub struct LittleEndian<V: SpecificEndian<B>, B: Copy = V>(pub(crate) B, pub(crate) PhantomData<V>);
 
@@ -216,6 +468,11 @@ where
     pub const fn from_bits(v: B) -> Self {
         Self(v, PhantomData)
     }
+    /// Reinterprets the raw data as another type with the same bit type `B`, e.g. `f32le` as `u32le`.  No bytes are
+    /// swapped, so this is free and keeps every bit, including NaN payloads.
+    pub const fn reinterpret<W: SpecificEndian<B>>(&self) -> LittleEndian<W, B> {
+        LittleEndian(self.0, PhantomData)
+    }
     /// Converts the data to the same type T in host-native endian.
     pub fn to_native(&self) -> V {
         V::from_little_endian(self.0)
@@ -228,13 +485,42 @@ impl<V: SpecificEndian<B>, B: Copy> From<V> for LittleEndian<V, B> {
     }
 }
 
-impl<V: SpecificEndian<B> + PartialEq, B: Copy> PartialEq for LittleEndian<V, B> {
+/// Parses the native value, e.g. `"1500".parse::<u16be>()`.
+impl<V: SpecificEndian<B> + FromStr, B: Copy> FromStr for LittleEndian<V, B> {
+    type Err = V::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        V::from_str(s).map(Self::from)
+    }
+}
+
+impl<V: EndianRepr<B> + PartialEq, B: Copy> PartialEq for LittleEndian<V, B> {
     fn eq(&self, other: &Self) -> bool {
-        self.to_native() == other.to_native()
+        V::eq_little_endian(&self.0, &other.0)
+    }
+}
+
+impl<V: EndianRepr<B> + Eq, B: Copy> Eq for LittleEndian<V, B> {}
+
+/// Hashes consistently with `PartialEq`, see `EndianRepr::hash_little_endian`.  `V: Eq` leaves out the floats, whose
+/// signed zeros compare equal but have different bits.
+impl<V: EndianRepr<B> + Eq, B: Copy + Hash> Hash for LittleEndian<V, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        V::hash_little_endian(&self.0, state)
+    }
+    fn hash_slice<H: Hasher>(data: &[Self], state: &mut H) {
+        // Safety: LittleEndian<V, B> is repr(transparent) over B.
+        let bits = unsafe { core::slice::from_raw_parts(data.as_ptr() as *const B, data.len()) };
+        V::hash_slice_little_endian(bits, state)
     }
 }
 
-impl<V: SpecificEndian<B> + Eq, B: Copy> Eq for LittleEndian<V, B> {}
+/// Shows the native value, see `fmt_debug`.
+impl<V: EndianRepr<B> + Debug, B: Copy> Debug for LittleEndian<V, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        fmt_debug(&self.to_native(), &self.0, "LittleEndian", "le", f)
+    }
+}
 
 #[cfg(feature = "big_endian")]
 mod big_endian_primatives {
@@ -350,6 +636,248 @@ mod both_endian_primatives {
     }
 }
 
+/// Const constructors, accessors and constants for the primitive wrappers.  `From`/`Into` can't be used in const
+/// contexts, so these allow magic numbers, lookup tables and default headers to be built at compile time.
+mod const_primitives {
+    #[allow(unused_imports)]
+    use super::*;
+
+    #[allow(unused_macros)]
+    macro_rules! make_const_integer {
+        ($wrap_ty:ident, $value_ty:ty, $to:ident, $from:ident) => {
+            impl $wrap_ty<$value_ty, $value_ty> {
+                /// The value 0.
+                pub const ZERO: Self = Self::new(0);
+                /// The value 1.
+                pub const ONE: Self = Self::new(1);
+                /// The smallest value that can be represented.
+                pub const MIN: Self = Self::new(<$value_ty>::MIN);
+                /// The largest value that can be represented.
+                pub const MAX: Self = Self::new(<$value_ty>::MAX);
+
+                /// Stores a native value, like `from()`, but usable in const contexts.
+                pub const fn new(v: $value_ty) -> Self {
+                    Self::from_bits(v.$to())
+                }
+                /// Returns the native value, like `to_native()`, but usable in const contexts.
+                pub const fn get(&self) -> $value_ty {
+                    <$value_ty>::$from(self.0)
+                }
+                /// Parses a native value in the given radix, like `from_str_radix()` on the primitive type.
+                pub fn from_str_radix(
+                    src: &str,
+                    radix: u32,
+                ) -> Result<Self, core::num::ParseIntError> {
+                    <$value_ty>::from_str_radix(src, radix).map(Self::new)
+                }
+            }
+        };
+    }
+
+    /// Zero-cost sign changes, like `cast_signed()` and `cast_unsigned()` on the primitive types.  Casting with `as`
+    /// keeps the bytes in place, so the result is still in the same endianness.
+    #[allow(unused_macros)]
+    macro_rules! make_const_sign_cast {
+        ($wrap_ty:ident, $unsigned_ty:ty, $signed_ty:ty) => {
+            impl $wrap_ty<$unsigned_ty, $unsigned_ty> {
+                /// Reinterprets the bits as the signed type of the same size, without any byte swap.
+                pub const fn cast_signed(&self) -> $wrap_ty<$signed_ty, $signed_ty> {
+                    $wrap_ty::from_bits(self.0 as $signed_ty)
+                }
+            }
+            impl $wrap_ty<$signed_ty, $signed_ty> {
+                /// Reinterprets the bits as the unsigned type of the same size, without any byte swap.
+                pub const fn cast_unsigned(&self) -> $wrap_ty<$unsigned_ty, $unsigned_ty> {
+                    $wrap_ty::from_bits(self.0 as $unsigned_ty)
+                }
+            }
+        };
+    }
+
+    #[allow(unused_macros)]
+    macro_rules! make_const_float {
+        ($wrap_ty:ident, $value_ty:ty, $bit_ty:ty, $to:ident, $from:ident) => {
+            impl $wrap_ty<$value_ty, $bit_ty> {
+                /// The value 0.0.
+                pub const ZERO: Self = Self::new(0.0);
+                /// The value 1.0.
+                pub const ONE: Self = Self::new(1.0);
+                /// The smallest finite value that can be represented.
+                pub const MIN: Self = Self::new(<$value_ty>::MIN);
+                /// The largest finite value that can be represented.
+                pub const MAX: Self = Self::new(<$value_ty>::MAX);
+
+                /// Stores a native value, like `from()`, but usable in const contexts.
+                pub const fn new(v: $value_ty) -> Self {
+                    Self::from_bits(v.to_bits().$to())
+                }
+                /// Returns the native value, like `to_native()`, but usable in const contexts.
+                pub const fn get(&self) -> $value_ty {
+                    <$value_ty>::from_bits(<$bit_ty>::$from(self.0))
+                }
+            }
+        };
+    }
+
+    #[allow(unused_macros)]
+    macro_rules! make_const_bool {
+        ($wrap_ty:ident) => {
+            impl $wrap_ty<bool, bool> {
+                /// Stores a native value, like `from()`, but usable in const contexts.
+                pub const fn new(v: bool) -> Self {
+                    Self::from_bits(v)
+                }
+                /// Returns the native value, like `to_native()`, but usable in const contexts.
+                pub const fn get(&self) -> bool {
+                    self.0
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "big_endian")]
+    mod be {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "byte_impls")]
+        make_const_bool!(BigEndian);
+        #[cfg(feature = "byte_impls")]
+        make_const_integer!(BigEndian, u8, to_be, from_be);
+        #[cfg(feature = "byte_impls")]
+        make_const_integer!(BigEndian, i8, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, u16, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, i16, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, u32, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, i32, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, u64, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, i64, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, u128, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, i128, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, usize, to_be, from_be);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(BigEndian, isize, to_be, from_be);
+        #[cfg(feature = "float_impls")]
+        make_const_float!(BigEndian, f32, u32, to_be, from_be);
+        #[cfg(feature = "float_impls")]
+        make_const_float!(BigEndian, f64, u64, to_be, from_be);
+        #[cfg(feature = "byte_impls")]
+        make_const_sign_cast!(BigEndian, u8, i8);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(BigEndian, u16, i16);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(BigEndian, u32, i32);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(BigEndian, u64, i64);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(BigEndian, u128, i128);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(BigEndian, usize, isize);
+    }
+
+    #[cfg(feature = "little_endian")]
+    mod le {
+        #[allow(unused_imports)]
+        use super::*;
+
+        #[cfg(feature = "byte_impls")]
+        make_const_bool!(LittleEndian);
+        #[cfg(feature = "byte_impls")]
+        make_const_integer!(LittleEndian, u8, to_le, from_le);
+        #[cfg(feature = "byte_impls")]
+        make_const_integer!(LittleEndian, i8, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, u16, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, i16, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, u32, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, i32, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, u64, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, i64, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, u128, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, i128, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, usize, to_le, from_le);
+        #[cfg(feature = "integer_impls")]
+        make_const_integer!(LittleEndian, isize, to_le, from_le);
+        #[cfg(feature = "float_impls")]
+        make_const_float!(LittleEndian, f32, u32, to_le, from_le);
+        #[cfg(feature = "float_impls")]
+        make_const_float!(LittleEndian, f64, u64, to_le, from_le);
+        #[cfg(feature = "byte_impls")]
+        make_const_sign_cast!(LittleEndian, u8, i8);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(LittleEndian, u16, i16);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(LittleEndian, u32, i32);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(LittleEndian, u64, i64);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(LittleEndian, u128, i128);
+        #[cfg(feature = "integer_impls")]
+        make_const_sign_cast!(LittleEndian, usize, isize);
+    }
+}
+
+/// Wraps raw bits that are already in big-endian order.  Used by `be!`, which needs a const way to fix `V` to `B`.
+#[doc(hidden)]
+pub const fn __be_from_bits<T: SpecificEndian<T>>(bits: T) -> BigEndian<T, T> {
+    BigEndian::from_bits(bits)
+}
+
+/// Wraps raw bits that are already in little-endian order.  Used by `le!`, which needs a const way to fix `V` to `B`.
+#[doc(hidden)]
+pub const fn __le_from_bits<T: SpecificEndian<T>>(bits: T) -> LittleEndian<T, T> {
+    LittleEndian::from_bits(bits)
+}
+
+/// Creates a `BigEndian` integer from a typed integer expression, usable in const contexts.
+///
+/// ```rust
+/// use simple_endian_wrapper::*;
+/// static MAGIC: u32be = be!(0xCAFEBABEu32);
+/// assert_eq!(MAGIC.to_native(), 0xCAFEBABE);
+/// ```
+///
+/// The type of the value must be known, so unsuffixed literals don't work.  For floats use e.g. `f32be::new()`.
+#[macro_export]
+macro_rules! be {
+    ($v:expr) => {
+        $crate::__be_from_bits(($v).to_be())
+    };
+}
+
+/// Creates a `LittleEndian` integer from a typed integer expression, usable in const contexts.
+///
+/// ```rust
+/// use simple_endian_wrapper::*;
+/// const TABLE: [u16le; 2] = [le!(1u16), le!(0x8000u16)];
+/// assert_eq!(TABLE[1].to_native(), 0x8000);
+/// ```
+///
+/// The type of the value must be known, so unsuffixed literals don't work.  For floats use e.g. `f32le::new()`.
+#[macro_export]
+macro_rules! le {
+    ($v:expr) => {
+        $crate::__le_from_bits(($v).to_le())
+    };
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -496,7 +1024,7 @@ mod tests {
         let mut be1 = BigEndian::from(1234);
         be1 &= BigEndian::from(5678);
         println!("{} {} {}", be1, be1.to_bits(), be1.to_native());
-        assert_eq!(be1, 1026.into());
+        assert_eq!(be1, 1026);
     }
 
     #[test]
@@ -504,23 +1032,23 @@ mod tests {
         let mut be1 = BigEndian::from(0x0feeddcc);
         be1 &= BigEndian::from(0xff00);
         println!("{} {} {}", be1, be1.to_bits(), be1.to_native());
-        assert_eq!(be1, 0xdd00.into());
+        assert_eq!(be1, 0xdd00);
     }
 
     #[test]
     fn mixed_endian_big() {
         let be = BigEndian::from(100);
         let le = LittleEndian::from(200);
-        let me = be + le.into();
-        assert_eq!(me, 300.into());
+        let me = be + le;
+        assert_eq!(me, 300);
     }
 
     #[test]
     fn mixed_endian_little() {
         let be = BigEndian::from(100);
         let le = LittleEndian::from(200);
-        let me = le + be.into();
-        assert_eq!(me, 300.into());
+        let me = le + be;
+        assert_eq!(me, 300);
     }
 
     #[test]
@@ -580,4 +1108,153 @@ mod tests {
         }
         assert_eq!(value, 0x0f000000000000000);
     }
+
+    #[test]
+    fn custom_type_hash_matches_eq() {
+        use std::collections::HashSet;
+
+        /// Every non-zero byte maps to `Flag(true)`.  It doesn't implement `Hash`, the wrappers hash the stored form.
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        struct Flag(bool);
+        impl SpecificEndian<u8> for Flag {
+            fn to_big_endian(&self) -> u8 {
+                self.0 as u8
+            }
+            fn to_little_endian(&self) -> u8 {
+                self.0 as u8
+            }
+            fn from_big_endian(value: u8) -> Self {
+                Flag(value != 0)
+            }
+            fn from_little_endian(value: u8) -> Self {
+                Flag(value != 0)
+            }
+        }
+        impl EndianRepr<u8> for Flag {}
+
+        let a = BigEndian::<Flag, u8>::from_bits(1);
+        let b = BigEndian::<Flag, u8>::from_bits(2);
+        assert_eq!(a, b);
+        assert_eq!(HashSet::from([a, b]).len(), 1);
+
+        let a = LittleEndian::<Flag, u8>::from_bits(1);
+        let b = LittleEndian::<Flag, u8>::from_bits(0xff);
+        assert_eq!(a, b);
+        assert_eq!(HashSet::from([a, b]).len(), 1);
+        assert_eq!(format!("{:?}", a), "LittleEndian(Flag(true))");
+    }
+
+    #[test]
+    fn debug_shows_native_value() {
+        #[derive(Debug)]
+        #[allow(dead_code)]
+        struct Header {
+            len: u16be,
+            offset: i32le,
+        }
+
+        assert_eq!(format!("{:?}", u32be::from(254)), "254_u32be");
+        assert_eq!(format!("{:?}", i16le::from(-2)), "-2_i16le");
+        assert_eq!(format!("{:?}", f32be::from(1.5)), "1.5_f32be");
+        assert_eq!(
+            format!("{:#?}", u32be::from(254)),
+            "254_u32be [00, 00, 00, fe]"
+        );
+        assert_eq!(
+            format!("{:#?}", u32le::from(254)),
+            "254_u32le [fe, 00, 00, 00]"
+        );
+        assert_eq!(
+            format!("{:#?}", f32be::from(1.5)),
+            "1.5_f32be [3f, c0, 00, 00]"
+        );
+        let nz = core::num::NonZeroU32::new(5).unwrap();
+        assert_eq!(format!("{:?}", BigEndian::from(nz)), "5_NonZeroU32be");
+        assert_eq!(
+            format!("{:#?}", LittleEndian::from(nz)),
+            "5_NonZeroU32le [05, 00, 00, 00]"
+        );
+        assert_eq!(
+            format!(
+                "{:?}",
+                Header {
+                    len: 20.into(),
+                    offset: (-1).into()
+                }
+            ),
+            "Header { len: 20_u16be, offset: -1_i32le }"
+        );
+        assert_eq!(
+            format!(
+                "{:#?}",
+                Header {
+                    len: 20.into(),
+                    offset: (-1).into()
+                }
+            ),
+            "Header {\n    len: 20_u16be [00, 14],\n    offset: -1_i32le [ff, ff, ff, ff],\n}"
+        );
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!("1500".parse::<u16be>(), Ok(u16be::from(1500)));
+        assert_eq!("-7".parse::<i64le>(), Ok(i64le::from(-7)));
+        assert_eq!("2.5".parse::<f32be>(), Ok(f32be::from(2.5)));
+        assert_eq!("true".parse::<BigEndian<bool>>(), Ok(BigEndian::from(true)));
+        assert_eq!(
+            "70000".parse::<u16le>(),
+            "70000".parse::<u16>().map(u16le::from)
+        );
+        assert!("x".parse::<f64le>().is_err());
+
+        assert_eq!(u32be::from_str_radix("cafe", 16), Ok(u32be::from(0xcafe)));
+        assert_eq!(i16le::from_str_radix("-101", 2), Ok(i16le::from(-5)));
+        assert_eq!(
+            u8::from_str_radix("777", 8).unwrap_err(),
+            BigEndian::<u8>::from_str_radix("777", 8).unwrap_err()
+        );
+    }
+
+    #[test]
+    fn const_construction() {
+        static MAGIC: u32be = be!(0xCAFEBABEu32);
+        const HEADER: [u16le; 2] = [u16le::new(7), le!(0x1234u16)];
+        const PI: f64be = f64be::new(3.5);
+        assert_eq!(MAGIC, 0xCAFEBABE);
+        assert_eq!(MAGIC.get(), 0xCAFEBABE);
+        assert_eq!(HEADER[0].get(), 7);
+        assert_eq!(HEADER[1], u16le::from(0x1234));
+        assert_eq!(PI.get(), 3.5);
+        assert!(BigEndian::<bool>::new(true).get());
+    }
+
+    #[test]
+    fn const_values() {
+        assert_eq!(i16be::MIN, i16::MIN);
+        assert_eq!(i16le::MAX, i16::MAX);
+        assert_eq!(u64be::ZERO, 0);
+        assert_eq!(u64le::ONE, 1);
+        assert_eq!(f32be::MAX, f32::MAX);
+        assert_eq!(f64le::ONE, 1.0);
+        assert_eq!(BigEndian::<u8>::MAX.to_bits(), 0xff);
+    }
+
+    #[test]
+    fn reinterpret_bits() {
+        const ONE: u32be = f32be::new(1.0).reinterpret();
+        assert_eq!(ONE, 1.0f32.to_bits());
+        assert_eq!(ONE.reinterpret::<f32>(), 1.0);
+
+        let nan = f64le::from_bits(0x7ff8_0000_dead_beefu64.to_le());
+        assert_eq!(nan.reinterpret::<u64>(), 0x7ff8_0000_dead_beef);
+
+        let minus_one = u32be::MAX.cast_signed();
+        assert_eq!(minus_one, -1);
+        assert_eq!(i16le::from(-2).cast_unsigned(), 0xfffe);
+        assert_eq!(
+            f32be::from(-0.0).reinterpret::<u32>().cast_signed(),
+            i32::MIN
+        );
+    }
 }
@@ -0,0 +1,111 @@
+//! Async extension traits for `tokio::io`, the counterparts of `ReadEndianExt` and `WriteEndianExt`.
+//!
+//! ```rust
+//! use simple_endian_wrapper::tokio_ext::{AsyncReadEndianExt, AsyncWriteEndianExt};
+//! use simple_endian_wrapper::*;
+//! use tokio::io::{AsyncRead, AsyncWrite};
+//!
+//! async fn scale<S: AsyncRead + AsyncWrite + Unpin>(stream: &mut S) -> std::io::Result<()> {
+//!     let id = stream.read_endian::<u32be>().await?;
+//!     let [value, factor] = stream.read_endian::<[f64le; 2]>().await?;
+//!     stream.write_endian(id).await?;
+//!     stream.write_endian(f64le::from(value.to_native() * factor.to_native())).await
+//! }
+//! ```
+//!
+//! Wire structs that implement [`EndianStruct`](crate::EndianStruct) are read and written as a whole, like the array
+//! above.  The futures are `Send` whenever the stream is.  The traits aren't exported from the crate root, see the
+//! `embedded` module.
+
+use std::io::Result;
+
+use ::tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+use super::*;
+
+/// Reads endian values from any `tokio::io::AsyncRead`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncReadEndianExt: AsyncRead + Unpin {
+    /// Reads one value or wire struct, e.g. `stream.read_endian::<u32be>().await`.
+    async fn read_endian<T: EndianBytes>(&mut self) -> Result<T> {
+        let mut v = T::zeroed();
+        self.read_exact(v.as_bytes_mut()).await?;
+        Ok(v)
+    }
+
+    /// Fills `values` directly from the stream.
+    async fn read_endian_into<T: EndianBytes>(&mut self, values: &mut [T]) -> Result<()> {
+        self.read_exact(T::slice_as_bytes_mut(values)).await?;
+        Ok(())
+    }
+}
+
+impl<R: AsyncRead + Unpin + ?Sized> AsyncReadEndianExt for R {}
+
+/// Writes endian values to any `tokio::io::AsyncWrite`.
+#[allow(async_fn_in_trait)]
+pub trait AsyncWriteEndianExt: AsyncWrite + Unpin {
+    /// Writes one value or wire struct, e.g. `stream.write_endian(u16le::from(7)).await`.
+    async fn write_endian<T: EndianBytes>(&mut self, v: T) -> Result<()> {
+        self.write_all(v.as_bytes()).await
+    }
+
+    /// Writes all `values` with a single `write_all`.
+    async fn write_endian_slice<T: EndianBytes>(&mut self, values: &[T]) -> Result<()> {
+        self.write_all(T::slice_as_bytes(values)).await
+    }
+}
+
+impl<W: AsyncWrite + Unpin + ?Sized> AsyncWriteEndianExt for W {}
+
+#[cfg(test)]
+mod tests {
+    use super::{AsyncReadEndianExt, AsyncWriteEndianExt};
+    use crate::test_util::block_on;
+    use crate::*;
+    use std::io::ErrorKind;
+
+    fn assert_send<T: Send>(v: T) -> T {
+        v
+    }
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    #[repr(C)]
+    struct Frame {
+        kind: u16be,
+        flags: u16le,
+        len: u32be,
+    }
+
+    unsafe impl EndianStruct for Frame {}
+
+    #[test]
+    fn duplex() {
+        let (mut client, mut server) = ::tokio::io::duplex(64);
+        let frame = Frame {
+            kind: 1.into(),
+            flags: 2.into(),
+            len: 3.into(),
+        };
+        block_on(async {
+            client.write_endian(frame).await.unwrap();
+            assert_send(client.write_endian_slice(&[f64le::from(0.5), f64le::from(-2.0)]))
+                .await
+                .unwrap();
+        });
+
+        block_on(async {
+            assert_eq!(
+                assert_send(server.read_endian::<Frame>()).await.unwrap(),
+                frame
+            );
+            let mut values = [f64le::from(0.0); 2];
+            server.read_endian_into(&mut values).await.unwrap();
+            assert_eq!(values, [f64le::from(0.5), f64le::from(-2.0)]);
+        });
+
+        drop(client);
+        let err = block_on(server.read_endian::<u16le>()).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+}
@@ -0,0 +1,144 @@
+//! Volatile registers with a declared endianness, for memory mapped I/O.
+//!
+//! Every access is a single `read_volatile` or `write_volatile` of the whole register, so it is neither optimised
+//! away nor torn, and the byte order is converted on the way in and out.  The types are `#[repr(transparent)]`, so
+//! register maps can be declared as `#[repr(C)]` structs and placed over the device memory.
+//!
+//! ```rust
+//! use simple_endian_wrapper::*;
+//!
+//! #[repr(C)]
+//! struct Registers {
+//!     control: VolatileBigEndian<u32>,
+//!     status: VolatileBigEndian<u32>,
+//! }
+//!
+//! // On a device this would be the register address instead of a buffer.
+//! let mut memory = [0u32; 2];
+//! let regs = unsafe { &*(memory.as_mut_ptr() as *const Registers) };
+//! regs.control.write(0x8000_0001);
+//! regs.control.modify(|v| v & !1);
+//! assert_eq!(regs.control.read(), 0x8000_0000);
+//! assert_eq!(memory[0], 0x8000_0000u32.to_be());
+//! ```
+
+use core::cell::UnsafeCell;
+use core::fmt::{Debug, Formatter};
+use core::ptr;
+
+use super::*;
+
+macro_rules! add_volatile {
+    ($volatile_ty:ident, $wrap_ty:ident) => {
+        /// A register holding a `V`, stored in the endianness of the wrapper with the same name, that is only ever
+        /// accessed with volatile reads and writes.
+        #[repr(transparent)]
+        pub struct $volatile_ty<V: SpecificEndian<B>, B: Copy = V>(UnsafeCell<$wrap_ty<V, B>>);
+
+        impl<V, B> $volatile_ty<V, B>
+        where
+            V: SpecificEndian<B>,
+            B: Copy,
+        {
+            /// Creates a register holding `v`, e.g. for a register map that is kept in RAM.
+            pub fn new(v: V) -> Self {
+                Self(UnsafeCell::new($wrap_ty::from(v)))
+            }
+
+            /// Gets a register at `ptr`, e.g. a device address.
+            ///
+            /// # Safety
+            ///
+            /// `ptr` must be valid for volatile reads and writes and properly aligned for as long as `'a`.
+            pub unsafe fn from_ptr<'a>(ptr: *mut $wrap_ty<V, B>) -> &'a Self {
+                // Safety: Self is repr(transparent) over the wrapper, the rest is up to the caller.
+                unsafe { &*(ptr as *const Self) }
+            }
+
+            /// Returns a raw pointer to the register.
+            pub const fn as_ptr(&self) -> *mut $wrap_ty<V, B> {
+                self.0.get()
+            }
+
+            /// Reads the register, without converting the byte order.
+            pub fn read_raw(&self) -> $wrap_ty<V, B> {
+                // Safety: the pointer comes from the UnsafeCell, so it is valid and aligned.
+                unsafe { ptr::read_volatile(self.0.get()) }
+            }
+
+            /// Writes the register, without converting the byte order.
+            pub fn write_raw(&self, v: $wrap_ty<V, B>) {
+                // Safety: the pointer comes from the UnsafeCell, so it is valid and aligned.
+                unsafe { ptr::write_volatile(self.0.get(), v) }
+            }
+
+            /// Reads the register and converts it to a native value.
+            pub fn read(&self) -> V {
+                self.read_raw().to_native()
+            }
+
+            /// Converts a native value and writes it to the register.
+            pub fn write(&self, v: V) {
+                self.write_raw($wrap_ty::from(v))
+            }
+
+            /// Reads the register, applies `f` to the native value and writes the result back.  This is not atomic.
+            pub fn modify<F: FnOnce(V) -> V>(&self, f: F) {
+                self.write(f(self.read()))
+            }
+        }
+
+        impl<V, B> Debug for $volatile_ty<V, B>
+        where
+            V: SpecificEndian<B> + Debug,
+            B: Copy,
+        {
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+                Debug::fmt(&self.read(), f)
+            }
+        }
+    };
+}
+
+add_volatile!(VolatileBigEndian, BigEndian);
+add_volatile!(VolatileLittleEndian, LittleEndian);
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use core::mem::size_of;
+
+    #[repr(C)]
+    struct Registers {
+        id: VolatileBigEndian<u16>,
+        flags: VolatileLittleEndian<u16>,
+        data: VolatileBigEndian<f32, u32>,
+    }
+
+    #[test]
+    fn register_map() {
+        assert_eq!(size_of::<Registers>(), 8);
+        let mut memory = [0u32; 2];
+        let regs = unsafe { &*(memory.as_mut_ptr() as *const Registers) };
+        regs.id.write(0x1234);
+        regs.flags.write(0x0001);
+        regs.data.write(2.5);
+        regs.flags.modify(|v| v | 0x8000);
+
+        let bytes: Vec<u8> = memory.iter().flat_map(|w| w.to_ne_bytes()).collect();
+        assert_eq!(bytes[..4], [0x12, 0x34, 0x01, 0x80]);
+        assert_eq!(bytes[4..], 2.5f32.to_be_bytes());
+        assert_eq!(regs.id.read(), 0x1234);
+        assert_eq!(regs.flags.read(), 0x8001);
+        assert_eq!(regs.data.read(), 2.5);
+    }
+
+    #[test]
+    fn from_ptr() {
+        let mut raw = u32be::from(7);
+        let reg = unsafe { VolatileBigEndian::from_ptr(&mut raw) };
+        reg.modify(|v| v * 6);
+        assert_eq!(reg.read_raw(), 42);
+        assert_eq!(raw, 42);
+    }
+}
@@ -0,0 +1,101 @@
+//! `winnow` parsers that produce endian wrappers and wire structs directly, instead of native values that have to be
+//! wrapped again.
+//!
+//! ```rust
+//! use simple_endian_wrapper::winnow_parsers::endian;
+//! use simple_endian_wrapper::*;
+//! use winnow::{ModalResult, Parser};
+//!
+//! fn chunk(input: &mut &[u8]) -> ModalResult<([u32be; 2], f32le)> {
+//!     (endian(), endian()).parse_next(input)
+//! }
+//!
+//! let mut input = &b"IHDR\x00\x00\x00\x0d\x00\x00\x80\x3f!"[..];
+//! let ([_, len], scale) = chunk(&mut input)?;
+//! assert_eq!(len, 13);
+//! assert_eq!(scale, 1.0);
+//! assert_eq!(endian::<u16le>().parse_peek(&b"\x4d\x5a"[..])?, (&b""[..], u16le::from(0x5a4d)));
+//! # Ok::<(), winnow::error::ErrMode<winnow::error::ContextError>>(())
+//! ```
+//!
+//! Wire structs that implement [`EndianStruct`](crate::EndianStruct) are parsed in one piece, like the array above.
+//! Short input fails with the error type of the caller, or with `Incomplete` on a `Partial` stream, like the binary
+//! parsers of `winnow`.
+
+use core::marker::PhantomData;
+
+use ::winnow::error::ParserError;
+use ::winnow::stream::{Stream, StreamIsPartial};
+use ::winnow::Parser;
+
+use super::*;
+
+/// The parser returned by `endian()`.
+pub struct Endian<T> {
+    _marker: PhantomData<fn() -> T>,
+}
+
+/// Parses a wrapper or wire struct, e.g. `endian::<u32be>()`.
+pub fn endian<T: EndianBytes>() -> Endian<T> {
+    Endian {
+        _marker: PhantomData,
+    }
+}
+
+impl<I, E, T> Parser<I, T, E> for Endian<T>
+where
+    I: StreamIsPartial + Stream<Token = u8>,
+    E: ParserError<I>,
+    T: EndianBytes,
+{
+    fn parse_next(&mut self, input: &mut I) -> Result<T, E> {
+        match input.offset_at(T::SIZE) {
+            Ok(offset) => {
+                let mut v = T::zeroed();
+                for (byte, (_, src)) in v.as_bytes_mut().iter_mut().zip(input.iter_offsets()) {
+                    *byte = src;
+                }
+                input.next_slice(offset);
+                Ok(v)
+            }
+            Err(needed) if I::is_partial_supported() && input.is_partial() => {
+                Err(E::incomplete(input, needed))
+            }
+            Err(_) => Err(E::from_input(input)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::endian;
+    use crate::*;
+    use winnow::error::{ContextError, ErrMode, InputError, Needed};
+    use winnow::stream::Partial;
+    use winnow::Parser;
+
+    #[test]
+    fn complete() {
+        let mut input = &[0xff, 0xfe, 1, 0, 2, 0, 3][..];
+        let result: Result<(i16be, [u16le; 2]), ContextError> =
+            (endian(), endian()).parse_next(&mut input);
+        assert_eq!(
+            result,
+            Ok((i16be::from(-2), [u16le::from(1), u16le::from(2)]))
+        );
+        assert_eq!(input, [3]);
+
+        let result = endian::<u32be>().parse_peek(&[1u8, 2][..]);
+        assert_eq!(result, Err(InputError::at(&[1u8, 2][..])));
+    }
+
+    #[test]
+    fn partial() {
+        let result: Result<_, ErrMode<ContextError>> =
+            endian::<[f64le; 2]>().parse_peek(Partial::new(&[0u8; 12][..]));
+        assert_eq!(result, Err(ErrMode::Incomplete(Needed::new(4))));
+        let result: Result<_, ErrMode<ContextError>> =
+            endian::<[f64le; 2]>().parse_peek(Partial::new(&[0u8; 16][..]));
+        assert_eq!(result.unwrap().1, [f64le::from(0.0); 2]);
+    }
+}